@@ -0,0 +1,201 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::PartResult;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CHECK_FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Accepted answers of a day, stored in `src/answers/YYYY/NN.txt`:
+///
+/// ```text
+/// part_one: 24000
+/// part_two: 45000
+/// ```
+///
+/// Lines for parts without an accepted answer can be omitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Answers {
+        let mut answers = Answers::default();
+        for line in contents.lines() {
+            let (key, value) = match line.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+            if value.is_empty() {
+                continue;
+            }
+            match key {
+                "part_one" => answers.part_one = Some(value.to_string()),
+                "part_two" => answers.part_two = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        answers
+    }
+
+    /// Loads the answers of `day` of `year`. A missing answers file yields no answers.
+    pub fn load(year: u16, day: u8) -> io::Result<Answers> {
+        Answers::load_from("answers", year, day)
+    }
+
+    /// Loads answers stored in `src/<folder>/<year>/<day>.txt`, e.g. the expected answers of
+    /// the example in `src/example_answers`.
+    pub fn load_from(folder: &str, year: u16, day: u8) -> io::Result<Answers> {
+        match crate::try_read_file(folder, year, day) {
+            Ok(contents) => Ok(Answers::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the answers to `src/<folder>/<year>/<day>.txt` and returns its path.
+    pub fn save_to(&self, folder: &str, year: u16, day: u8) -> io::Result<PathBuf> {
+        let path = crate::file_path(folder, year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, self.to_string())?;
+        Ok(path)
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, result: &PartResult) -> CheckStatus {
+        match (self.get(result.part), &result.answer) {
+            (None, _) => CheckStatus::Missing,
+            (Some(expected), Some(answer)) if expected == answer => CheckStatus::Ok,
+            (Some(expected), _) => CheckStatus::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(answer) = &self.part_one {
+            writeln!(f, "part_one: {}", answer)?;
+        }
+        if let Some(answer) = &self.part_two {
+            writeln!(f, "part_two: {}", answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of comparing a part's answer with the accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Ok,
+    Wrong {
+        expected: String,
+    },
+    /// No accepted answer is stored for the part.
+    Missing,
+}
+
+impl CheckStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "OK",
+            CheckStatus::Wrong { .. } => "WRONG",
+            CheckStatus::Missing => "MISSING",
+        }
+    }
+}
+
+/// Checks `result` against the stored answers of its day in `year` and remembers mismatches for [`check_failed`].
+pub fn check(year: u16, result: &PartResult) -> CheckStatus {
+    let status = match Answers::load(year, result.day) {
+        Ok(answers) => answers.check(result),
+        Err(e) => {
+            eprintln!("Failed to read answers for day {:02}: {}", result.day, e);
+            CheckStatus::Missing
+        }
+    };
+    if matches!(status, CheckStatus::Wrong { .. }) {
+        CHECK_FAILED.store(true, Ordering::Relaxed);
+    }
+    status
+}
+
+/// Whether any part checked by this process gave a wrong answer.
+pub fn check_failed() -> bool {
+    CHECK_FAILED.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: 1,
+            part,
+            answer: answer.map(|a| a.to_string()),
+            error: None,
+            elapsed: Duration::ZERO,
+            stats: None,
+            check: None,
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part_one: 24000\npart_two:  CMZ \n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("CMZ"));
+
+        let answers = Answers::parse("part_two: 45000\npart_one:\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("45000"));
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part_one: 24000\n");
+        assert_eq!(answers.check(&result(1, Some("24000"))), CheckStatus::Ok);
+        assert_eq!(
+            answers.check(&result(1, Some("23999"))),
+            CheckStatus::Wrong {
+                expected: "24000".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(&result(1, None)),
+            CheckStatus::Wrong {
+                expected: "24000".to_string()
+            }
+        );
+        assert_eq!(answers.check(&result(2, Some("1"))), CheckStatus::Missing);
+    }
+}
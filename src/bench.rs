@@ -0,0 +1,148 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::registry::Run;
+use crate::report::nanos;
+use crate::{ANSI_BOLD, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Time spent calling a solver before samples are recorded.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+/// Time the recorded samples of one part should roughly take.
+const TARGET_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10_000;
+
+/// Summary of repeated timings of one part. In JSON, the durations are whole nanoseconds, e.g. `"min_ns":1250`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes statistics over `timings`. Returns `None` if there are no timings.
+    pub fn from_timings(timings: &[Duration]) -> Option<Stats> {
+        if timings.is_empty() {
+            return None;
+        }
+
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|t| t.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Warms up `solver`, then calls it repeatedly and returns the first run together with the timing statistics.
+/// The number of samples is chosen so that a part takes about [`TARGET_TIME`] in total.
+pub fn bench(solver: impl Fn(&str) -> Run, input: &str) -> (Run, Stats) {
+    let first = solver(input);
+
+    let mut warmup_runs = 1;
+    let mut warmup_elapsed = first.elapsed;
+    while warmup_elapsed < WARMUP_TIME && warmup_runs < MAX_SAMPLES {
+        warmup_elapsed += solver(input).elapsed;
+        warmup_runs += 1;
+    }
+
+    let average = warmup_elapsed / warmup_runs as u32;
+    let samples = if average.is_zero() {
+        MAX_SAMPLES
+    } else {
+        (TARGET_TIME.as_nanos() / average.as_nanos()) as usize
+    }
+    .clamp(MIN_SAMPLES, MAX_SAMPLES);
+
+    let timings: Vec<Duration> = (0..samples).map(|_| solver(input).elapsed).collect();
+    let stats = Stats::from_timings(&timings).expect("at least one sample");
+
+    (first, stats)
+}
+
+/// Prints the statistics of `(day, part, stats)` rows as an aligned table.
+pub fn print_table(rows: &[(u8, u8, Stats)]) {
+    println!(
+        "{}| Day | Part | Samples |        Min |     Median |       Mean |    Std dev |{}",
+        ANSI_BOLD, ANSI_RESET
+    );
+    println!("|-----|------|---------|------------|------------|------------|------------|");
+    for (day, part, stats) in rows {
+        println!(
+            "| {:>3} | {:>4} | {:>7} | {:>10} | {:>10} | {:>10} | {:>10} |",
+            format!("{:02}", day),
+            part,
+            stats.samples,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.stddev),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_nanos(v)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_timings() {
+        let stats = Stats::from_timings(&nanos(&[9, 2, 4, 4, 5, 5, 7, 4])).unwrap();
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(4));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+
+        let stats = Stats::from_timings(&nanos(&[30, 10, 20])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(20));
+
+        assert_eq!(Stats::from_timings(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_keeps_sub_microsecond_precision() {
+        let solver = |input: &str| Run {
+            answer: Some(input.to_string()),
+            error: None,
+            elapsed: Duration::from_nanos(250),
+            alloc: None,
+        };
+        let (run, stats) = bench(solver, "42");
+        assert_eq!(run.answer, Some("42".to_string()));
+        assert_eq!(stats.samples, MAX_SAMPLES);
+        assert_eq!(stats.min, Duration::from_nanos(250));
+        assert_eq!(stats.median, Duration::from_nanos(250));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::puzzle::Example;
+use advent_of_code::DEFAULT_YEAR;
+use std::path::PathBuf;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    puzzle: PathBuf,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        puzzle: args
+            .opt_value_from_str("--puzzle")?
+            .unwrap_or_else(|| PathBuf::from("puzzle.md")),
+        force: args.contains("--force"),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let markdown = match fs::read_to_string(&args.puzzle) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!(
+                "Failed to read puzzle description \"{}\": {}",
+                args.puzzle.display(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let example = Example::parse(&markdown);
+    if let Some(day) = example.day.filter(|&day| day != args.day) {
+        eprintln!(
+            "\"{}\" describes day {}, not day {}.",
+            args.puzzle.display(),
+            day,
+            args.day
+        );
+        process::exit(1);
+    }
+
+    match &example.input {
+        Some(input) => {
+            let example_path = advent_of_code::file_path("examples", args.year, args.day)
+                .expect("could not determine example path");
+            let existing = fs::read_to_string(&example_path).unwrap_or_default();
+            if !existing.trim().is_empty() && existing != *input && !args.force {
+                eprintln!(
+                    "\"{}\" already contains a different example. Pass --force to overwrite it.",
+                    example_path.display()
+                );
+                process::exit(1);
+            }
+            if let Err(e) = fs::create_dir_all(example_path.parent().unwrap())
+                .and_then(|_| fs::write(&example_path, input))
+            {
+                eprintln!("Failed to write example file: {}", e);
+                process::exit(1);
+            }
+            println!("Wrote example input to \"{}\"", example_path.display());
+        }
+        None => println!("No example input found."),
+    }
+
+    // keep answers of parts that are no longer (or not yet) in the description.
+    let mut expected = match Answers::load_from("example_answers", args.year, args.day) {
+        Ok(expected) => expected,
+        Err(e) => {
+            eprintln!("Failed to read expected answers: {}", e);
+            process::exit(1);
+        }
+    };
+    for part in [1, 2] {
+        match example.answers.get(part) {
+            Some(answer) => {
+                println!(
+                    "Part {}: expected answer for the example is {}",
+                    part, answer
+                );
+                expected.set(part, answer);
+            }
+            None => println!("Part {}: no expected answer found.", part),
+        }
+    }
+
+    match expected.save_to("example_answers", args.year, args.day) {
+        Ok(path) => println!("Wrote expected answers to \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write expected answers: {}", e);
+            process::exit(1);
+        }
+    }
+}
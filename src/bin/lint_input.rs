@@ -0,0 +1,101 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::lint::{self, Issue};
+use advent_of_code::DEFAULT_YEAR;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    normalize: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        normalize: args.contains("--normalize"),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let input_path = match advent_of_code::file_path("inputs", args.year, args.day) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("could not determine input path: {}", e);
+            process::exit(1);
+        }
+    };
+    // read bytes, an input that is not UTF-8 is one of the issues to report.
+    let contents = match fs::read(&input_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", input_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    if contents.is_empty() {
+        println!(
+            "\"{}\" is empty, run `cargo download {}` first.",
+            input_path.display(),
+            args.day
+        );
+        process::exit(1);
+    }
+
+    let issues = lint::lint(&contents);
+    if issues.is_empty() {
+        println!("🎄 \"{}\" looks fine.", input_path.display());
+        return;
+    }
+
+    println!("\"{}\":", input_path.display());
+    for issue in &issues {
+        println!("  {}", issue);
+    }
+
+    let remaining: Vec<&Issue> = match (args.normalize, String::from_utf8(contents)) {
+        (true, Ok(contents)) => {
+            let normalized = lint::normalize(&contents);
+            if let Err(e) = fs::write(&input_path, normalized) {
+                eprintln!("Failed to write \"{}\": {}", input_path.display(), e);
+                process::exit(1);
+            }
+            println!("---");
+            println!("Normalized line endings and trailing newlines.");
+            issues.iter().filter(|issue| !issue.is_fixable()).collect()
+        }
+        (true, Err(_)) => {
+            println!("---");
+            println!("Not normalized, the input is not valid UTF-8. Download it again with `cargo download {} --force`.", args.day);
+            issues.iter().collect()
+        }
+        (false, _) => issues.iter().collect(),
+    };
+
+    let is_utf8 = !remaining
+        .iter()
+        .any(|issue| matches!(issue, Issue::InvalidUtf8 { .. }));
+    if !args.normalize && is_utf8 && remaining.iter().any(|issue| issue.is_fixable()) {
+        println!(
+            "Pass --normalize to fix the byte order mark, line endings and trailing newlines."
+        );
+    }
+    if !remaining.is_empty() {
+        process::exit(1);
+    }
+}
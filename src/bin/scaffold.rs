@@ -2,60 +2,186 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::answers::Answers;
+use advent_of_code::template::{self, Placeholders};
+use advent_of_code::{bin_name, day_module, DEFAULT_YEAR};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// Used when `templates/default.rs` was removed from the project.
+const DEFAULT_TEMPLATE: &str = include_str!("../../templates/default.rs");
+const TEMPLATE_DIR: &str = "templates";
+
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
+    return_type: String,
+    part_one: Option<String>,
+    part_two: Option<String>,
+    /// Rewrite the tests of an existing module instead of creating one.
+    update_tests: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let update_day: Option<u8> = args.opt_value_from_str("--update-tests")?;
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| "default".to_string()),
+        return_type: args
+            .opt_value_from_str("--type")?
+            .unwrap_or_else(|| "u32".to_string()),
+        part_one: args.opt_value_from_str("--p1")?,
+        part_two: args.opt_value_from_str("--p2")?,
+        update_tests: update_day.is_some(),
+        day: match update_day {
+            Some(day) => day,
+            None => args.free_from_str()?,
+        },
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Loads `templates/<name>.rs`.
+fn load_template(name: &str) -> Result<String, std::io::Error> {
+    let path = Path::new(TEMPLATE_DIR).join(format!("{}.rs", name));
+    match fs::read_to_string(&path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && name == "default" => {
+            Ok(DEFAULT_TEMPLATE.to_string())
+        }
+        result => {
+            result.map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+/// Creates an empty file unless it already exists, e.g. because the input was downloaded first.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    match safe_create_file(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
     }
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+/// The binary that solves a single day with the solution in `advent_of_code::days`.
+fn bin_source(year: u16, day: u8) -> String {
+    let read_input = if year == DEFAULT_YEAR {
+        format!("read_input!({})", day)
+    } else {
+        format!("read_input!({}, {})", year, day)
+    };
+    format!(
+        r#"use advent_of_code::days::{}::{{part_one, part_two}};
+
+fn main() {{
+    let input = &advent_of_code::{};
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}}
+"#,
+        day_module(year, day),
+        read_input
+    )
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Adds `pub mod <name>;` to the module file at `parent_path`, creating it with `header` if it does not exist.
+fn declare_module(parent_path: &str, name: &str, header: &str) -> Result<bool, std::io::Error> {
+    let parent = match fs::read_to_string(parent_path) {
+        Ok(parent) => parent,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => header.to_string(),
+        Err(e) => return Err(e),
+    };
+    let declaration = format!("pub mod {};", name);
+    if parent.lines().any(|line| line.trim() == declaration) {
+        return Ok(false);
+    }
+
+    // keep the declarations together, after the last existing one.
+    let mut lines: Vec<&str> = parent.lines().collect();
+    let index = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod "))
+        .map_or(lines.len(), |index| index + 1);
+    lines.insert(index, &declaration);
+
+    fs::write(parent_path, lines.join("\n") + "\n")?;
+    Ok(true)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Adds the day to the `days!` list in the runner so `cargo all` picks it up.
+fn register_day(runner_path: &str, year: u16, day: u8) -> Result<bool, std::io::Error> {
+    let runner = fs::read_to_string(runner_path)?;
+    let entry = format!("    ({}, {}) => {},", year, day, day_module(year, day));
+
+    if runner.lines().any(|line| line == entry) {
+        return Ok(false);
+    }
+
+    let start = runner.find("days! {\n").ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "no `days!` list found")
+    })?;
+    let end = start + runner[start..].find("\n}").unwrap_or(runner.len() - start);
+
+    let mut updated = runner[..end].to_string();
+    updated.push('\n');
+    updated.push_str(&entry);
+    updated.push_str(&runner[end..]);
+
+    fs::write(runner_path, updated)?;
+    Ok(true)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Rewrites the assertions of an existing module to expect `expected`.
+fn update_tests(module_path: &str, expected: &Answers) {
+    let module = match fs::read_to_string(module_path) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to read module file \"{}\": {}", module_path, e);
+            process::exit(1);
+        }
+    };
+
+    let (module, updated) = template::update_tests(&module, expected);
+    if updated.is_empty() {
+        println!(
+            "No tests updated. Pass --p1/--p2 or run `cargo extract` to record the expected answers."
+        );
+        return;
+    }
+
+    if let Err(e) = fs::write(module_path, module) {
+        eprintln!("Failed to write module file: {}", e);
+        process::exit(1);
+    }
+    for (part, expected) in updated {
+        println!("Updated part {} test to expect {}", part, expected);
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        year,
+        template,
+        return_type,
+        part_one,
+        part_two,
+        update_tests: update_only,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
@@ -63,10 +189,58 @@ fn main() {
     };
 
     let day_padded = format!("{:02}", day);
+    let bin = bin_name(year, day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = format!("src/inputs/{}/{}.txt", year, day_padded);
+    let example_path = format!("src/examples/{}/{}.txt", year, day_padded);
+    let module_name = day_module(year, day);
+    let module_path = format!("src/days/{}.rs", module_name.replace("::", "/"));
+    let bin_path = format!("src/bin/{}.rs", bin);
+    let runner_path = "src/main.rs";
+
+    // expected answers written by `cargo extract`, if it ran before, unless passed explicitly.
+    let mut expected = Answers::load_from("example_answers", year, day).unwrap_or_default();
+    if part_one.is_some() || part_two.is_some() {
+        for (part, answer) in [(1, &part_one), (2, &part_two)] {
+            if let Some(answer) = answer {
+                expected.set(part, answer);
+            }
+        }
+        if let Err(e) = expected.save_to("example_answers", year, day) {
+            eprintln!("Failed to write expected answers: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if update_only {
+        update_tests(&module_path, &expected);
+        return;
+    }
+
+    for dir in [&input_path, &example_path, &module_path] {
+        if let Err(e) = fs::create_dir_all(Path::new(dir).parent().unwrap()) {
+            eprintln!("Failed to create directory for \"{}\": {}", dir, e);
+            process::exit(1);
+        }
+    }
+
+    let template = match load_template(&template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let module = template::render(
+        &template,
+        &Placeholders {
+            year,
+            day,
+            return_type: &return_type,
+            expected: &expected,
+        },
+    );
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,7 +250,7 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -86,10 +260,54 @@ fn main() {
         }
     }
 
-    match create_file(&input_path) {
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(bin_source(year, day).as_bytes()) {
         Ok(_) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // days of other years live in a module per year, e.g. `src/days/y2021.rs`.
+    let declarations = match module_name.split_once("::") {
+        Some((year_module, day_module)) => vec![
+            ("src/days.rs".to_string(), year_module, String::new()),
+            (
+                format!("src/days/{}.rs", year_module),
+                day_module,
+                format!("//! The solutions of {}.\n", year),
+            ),
+        ],
+        None => vec![(
+            "src/days.rs".to_string(),
+            module_name.as_str(),
+            String::new(),
+        )],
+    };
+    for (parent_path, name, header) in declarations {
+        if let Err(e) = declare_module(&parent_path, name, &header) {
+            eprintln!("Failed to declare module in \"{}\": {}", parent_path, e);
+            process::exit(1);
+        }
+    }
+
+    match create_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
             process::exit(1);
@@ -97,18 +315,29 @@ fn main() {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
             process::exit(1);
         }
     }
 
+    match register_day(runner_path, year, day) {
+        Ok(true) => {
+            println!("Registered day in \"{}\"", runner_path);
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register day in runner: {}", e);
+            process::exit(1);
+        }
+    }
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin);
 }
@@ -0,0 +1,125 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::client::Client;
+use advent_of_code::report::PartResult;
+use advent_of_code::{bin_name, DEFAULT_YEAR};
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+    base_url: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        base_url: args.opt_value_from_str("--base-url")?,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+/// Solves `part` of `day` of `year` with the day's binary and returns its answer.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let output = Command::new(cargo)
+        .args(["run", "--release", "--quiet", "--bin", &bin_name(year, day)])
+        .args(["--", "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run day {:02}: {}", day, e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let result = stdout
+        .lines()
+        .filter_map(PartResult::from_json)
+        .find(|result| result.part == part);
+
+    match result {
+        Some(PartResult {
+            answer: Some(answer),
+            ..
+        }) => Ok(answer),
+        Some(PartResult {
+            error: Some(error), ..
+        }) => Err(error),
+        Some(_) => Err(format!("part {} is not solved.", part)),
+        None if !output.status.success() => {
+            Err(format!("day {:02} failed: {}", day, output.status))
+        }
+        None => Err(format!(
+            "day {:02} printed no answer for part {}.",
+            day, part
+        )),
+    }
+}
+
+/// Stores an accepted answer in `src/answers/YYYY/NN.txt` for `--check`.
+fn save_answer(year: u16, day: u8, part: u8, answer: &str) -> std::io::Result<PathBuf> {
+    let mut answers = Answers::load(year, day)?;
+    answers.set(part, answer);
+    answers.save_to("answers", year, day)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if !(1..=2).contains(&args.part) {
+        eprintln!("part must be 1 or 2.");
+        process::exit(1);
+    }
+
+    let client = match Client::from_env(args.base_url, args.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let answer = match solve(args.year, args.day, args.part) {
+        Ok(answer) => answer,
+        Err(e) => {
+            eprintln!("Could not solve day {:02}: {}", args.day, e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Submitting \"{}\" for day {:02}, part {}...",
+        answer, args.day, args.part
+    );
+    let outcome = match client.submit(args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+    println!("{}", outcome);
+
+    if !outcome.is_correct() {
+        process::exit(1);
+    }
+    match save_answer(args.year, args.day, args.part, &answer) {
+        Ok(path) => println!("🎄 Saved answer to \"{}\".", path.display()),
+        Err(e) => eprintln!("could not save answer: {}", e),
+    }
+}
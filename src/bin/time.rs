@@ -0,0 +1,116 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::readme_benchmarks::{self, MARKER};
+use advent_of_code::report::PartResult;
+use advent_of_code::{day_module, DEFAULT_YEAR};
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::{fs, io};
+
+struct Args {
+    year: u16,
+    bench: bool,
+    readme: PathBuf,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        bench: args.contains("--bench"),
+        readme: args
+            .opt_value_from_str("--readme")?
+            .unwrap_or_else(|| PathBuf::from("README.md")),
+    })
+}
+
+/// Solves every day of `year` with the `all` runner in release mode.
+fn solve_all(year: u16, bench: bool) -> io::Result<Vec<PartResult>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command
+        .args(["run", "--release", "--quiet", "--bin", "advent_of_code"])
+        .args(["--", "--format", "json", "--year", &year.to_string()]);
+    if bench {
+        command.arg("--bench");
+    }
+
+    let output = command.stderr(Stdio::inherit()).output()?;
+    // days that panicked or timed out are reported above, parts without a result are listed as not solved.
+    if !output.status.success() {
+        eprintln!("warning: the runner exited with {}", output.status);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(PartResult::from_json)
+        .collect())
+}
+
+/// The last day of `year` that has a solution module, so days without any result still get a row.
+fn last_day(year: u16) -> u8 {
+    (1..=25)
+        .rev()
+        .find(|&day| {
+            let module = day_module(year, day).replace("::", "/");
+            Path::new(&format!("src/days/{}.rs", module)).exists()
+        })
+        .unwrap_or(0)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // read the readme first, there is no point in solving everything if it can't be updated.
+    let readme = match fs::read_to_string(&args.readme) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", args.readme.display(), e);
+            process::exit(1);
+        }
+    };
+
+    println!("Solving all days of {}...", args.year);
+    let results = match solve_all(args.year, args.bench) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Failed to solve: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let table = readme_benchmarks::table(&results, last_day(args.year));
+    let updated = match readme_benchmarks::update(&readme, &table) {
+        Some(updated) => updated,
+        None => {
+            eprintln!(
+                "\"{}\" needs two `{}` lines to mark where the table goes.",
+                args.readme.display(),
+                MARKER
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = fs::write(&args.readme, updated) {
+        eprintln!("Failed to write \"{}\": {}", args.readme.display(), e);
+        process::exit(1);
+    }
+    println!("---");
+    println!(
+        "🎄 Wrote benchmarks of {} parts to \"{}\".",
+        results.len(),
+        args.readme.display()
+    );
+}
@@ -0,0 +1,194 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::report::PartResult;
+use advent_of_code::{bin_name, day_module, DEFAULT_YEAR};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Modification times of `paths` and of the files in them, if they are directories.
+/// Missing files are recorded as well, so creating them counts as a change.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    let mut times = BTreeMap::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        match fs::read_dir(&path) {
+            Ok(entries) => pending.extend(entries.flatten().map(|entry| entry.path())),
+            Err(_) => {
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                times.insert(path, modified);
+            }
+        }
+    }
+    times
+}
+
+fn changed_paths<'a>(
+    before: &'a BTreeMap<PathBuf, Option<SystemTime>>,
+    after: &'a BTreeMap<PathBuf, Option<SystemTime>>,
+) -> Vec<&'a Path> {
+    let mut changed: Vec<&Path> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.as_path())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .map(PathBuf::as_path),
+    );
+    changed
+}
+
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo")))
+}
+
+/// Runs the unit tests of the day's module, which use the example input.
+fn run_tests(module: &str) -> bool {
+    let filter = format!("days::{}::", module);
+    match cargo().args(["test", "--quiet", "--lib", &filter]).status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to run tests: {}", e);
+            false
+        }
+    }
+}
+
+/// Solves the day with its binary, `None` if it failed to build or run.
+fn run_solution(bin: &str) -> Option<Vec<PartResult>> {
+    let output = cargo()
+        .args(["run", "--release", "--quiet", "--bin", bin])
+        .args(["--", "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => Some(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(PartResult::from_json)
+                .collect(),
+        ),
+        Ok(output) => {
+            eprintln!("Solution failed: {}", output.status);
+            None
+        }
+        Err(e) => {
+            eprintln!("Failed to run solution: {}", e);
+            None
+        }
+    }
+}
+
+fn describe(result: &PartResult) -> String {
+    match (&result.answer, &result.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(error)) => format!("error: {}", error),
+        (None, None) => "✖".to_string(),
+    }
+}
+
+/// Prints the answers of `current`, marking those that differ from the `previous` run.
+fn print_answers(previous: &[PartResult], current: &[PartResult]) {
+    for result in current {
+        let answer = describe(result);
+        let before = previous
+            .iter()
+            .find(|before| before.part == result.part)
+            .map(describe);
+        match before {
+            Some(before) if before == answer => {
+                println!("Part {}: {} (unchanged)", result.part, answer)
+            }
+            Some(before) => println!("Part {}: {} (was {})", result.part, answer, before),
+            None => println!("Part {}: {}", result.part, answer),
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let bin = bin_name(args.year, args.day);
+    let module = day_module(args.year, args.day);
+    let mut watched = vec![
+        PathBuf::from(format!("src/days/{}.rs", module.replace("::", "/"))),
+        PathBuf::from(format!("src/bin/{}.rs", bin)),
+        PathBuf::from("src/helpers.rs"),
+        PathBuf::from("src/helpers"),
+    ];
+    for folder in ["examples", "inputs"] {
+        match advent_of_code::file_path(folder, args.year, args.day) {
+            Ok(path) => watched.push(path),
+            Err(e) => {
+                eprintln!("could not determine {} path: {}", folder, e);
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("👀 Watching day {:02}, press Ctrl+C to stop.", args.day);
+    for path in &watched {
+        println!("  {}", path.display());
+    }
+
+    let mut times = snapshot(&watched);
+    let mut previous = vec![];
+    loop {
+        println!("---");
+        if !run_tests(&module) {
+            println!("Example tests failed.");
+        }
+        if let Some(results) = run_solution(&bin) {
+            print_answers(&previous, &results);
+            previous = results;
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&watched);
+            let changed = changed_paths(&times, &current);
+            if !changed.is_empty() {
+                println!();
+                for path in changed {
+                    println!("Changed: {}", path.display());
+                }
+                times = current;
+                break;
+            }
+        }
+    }
+}
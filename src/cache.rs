@@ -0,0 +1,302 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Name of the manifest that records the checksums of downloaded inputs, stored next to them.
+pub const MANIFEST_FILE: &str = "checksums.txt";
+/// How long [`record`] waits for another download to release the lock of the manifest.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+/// Recording a checksum takes milliseconds, so an older lock was left behind by a process that crashed.
+const STALE_LOCK: Duration = Duration::from_secs(5);
+
+/// FNV-1a hash of `contents` as 16 hex digits.
+/// This detects accidental changes to an input, it is not meant to withstand deliberate ones.
+pub fn checksum(contents: &str) -> String {
+    let hash = contents.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Checksums of the inputs in one directory, stored as `<checksum>  <file name>` lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    pub fn parse(contents: &str) -> Manifest {
+        let entries = contents
+            .lines()
+            .filter_map(|line| line.split_once("  "))
+            .map(|(checksum, name)| (name.trim().to_string(), checksum.trim().to_string()))
+            .collect();
+        Manifest { entries }
+    }
+
+    /// Loads the manifest of `dir`. A missing manifest is empty.
+    pub fn load(dir: &Path) -> io::Result<Manifest> {
+        match fs::read_to_string(dir.join(MANIFEST_FILE)) {
+            Ok(contents) => Ok(Manifest::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(MANIFEST_FILE), self.to_string())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries.get(name).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, name: &str, checksum: String) {
+        self.entries.insert(name.to_string(), checksum);
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, checksum) in &self.entries {
+            writeln!(f, "{}  {}", checksum, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// How an input compares with the checksum recorded when it was downloaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputStatus {
+    Unchanged,
+    /// The input was not downloaded with `cargo download` or predates the manifest.
+    Unrecorded,
+    /// The input only differs by `\r\n` line endings, e.g. after a git checkout on Windows.
+    CrlfConverted,
+    Modified,
+}
+
+impl InputStatus {
+    pub fn of(contents: &str, recorded: Option<&str>) -> InputStatus {
+        match recorded {
+            None => InputStatus::Unrecorded,
+            Some(recorded) if checksum(contents) == recorded => InputStatus::Unchanged,
+            Some(recorded)
+                if contents.contains("\r\n")
+                    && checksum(&contents.replace("\r\n", "\n")) == recorded =>
+            {
+                InputStatus::CrlfConverted
+            }
+            Some(_) => InputStatus::Modified,
+        }
+    }
+}
+
+fn split_path(path: &Path) -> (PathBuf, String) {
+    let dir = path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+    let name = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    (dir, name)
+}
+
+/// Records the checksum of the input at `path` in the manifest of its directory.
+/// A lock file serializes concurrent downloads, so none of their entries get lost.
+pub fn record(path: &Path, contents: &str) -> io::Result<()> {
+    let (dir, name) = split_path(path);
+    let lock_path = dir.join(format!("{}.lock", MANIFEST_FILE));
+
+    let deadline = Instant::now() + LOCK_TIMEOUT;
+    while let Err(e) = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&lock_path)
+    {
+        if e.kind() == io::ErrorKind::AlreadyExists && is_stale(&lock_path) {
+            remove_stale_lock(&lock_path)?;
+            continue;
+        }
+        if e.kind() != io::ErrorKind::AlreadyExists {
+            return Err(io::Error::new(
+                e.kind(),
+                format!("could not lock {}: {}", lock_path.display(), e),
+            ));
+        }
+        if Instant::now() > deadline {
+            return Err(io::Error::new(
+                e.kind(),
+                format!(
+                    "{} is still locked after {:?}. If no download is running, delete {}.",
+                    dir.join(MANIFEST_FILE).display(),
+                    LOCK_TIMEOUT,
+                    lock_path.display()
+                ),
+            ));
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let result = Manifest::load(&dir).and_then(|mut manifest| {
+        manifest.insert(&name, checksum(contents));
+        manifest.save(&dir)
+    });
+    fs::remove_file(&lock_path)?;
+    result
+}
+
+/// Removes the lock file at `path` if it is stale. Another download may replace a stale lock with its own
+/// at any time, so the lock is moved to a name of this process first and only deleted if that file is stale.
+fn remove_stale_lock(path: &Path) -> io::Result<()> {
+    let taken = path.with_extension(format!("lock.{}", process::id()));
+    match fs::rename(path, &taken) {
+        // another download removed it first.
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+        Ok(()) => {}
+    }
+    if is_stale(&taken) {
+        return fs::remove_file(&taken);
+    }
+
+    // the stale lock was replaced by a fresh one in the meantime, give it back to its download.
+    let restored = fs::hard_link(&taken, path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("could not restore the lock {}: {}", path.display(), e),
+        )
+    });
+    fs::remove_file(&taken)?;
+    restored
+}
+
+/// Whether the lock file at `path` is older than [`STALE_LOCK`].
+fn is_stale(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age > STALE_LOCK)
+}
+
+/// Compares the input at `path` with the checksum recorded for it.
+pub fn verify(path: &Path, contents: &str) -> io::Result<InputStatus> {
+    let (dir, name) = split_path(path);
+    let manifest = Manifest::load(&dir)?;
+    Ok(InputStatus::of(contents, manifest.get(&name)))
+}
+
+/// Prints a warning if the input at `path` no longer matches its downloaded version.
+pub fn warn_if_modified(path: &Path, contents: &str) {
+    let reason = match verify(path, contents) {
+        Ok(InputStatus::CrlfConverted) => "its line endings were converted to CRLF",
+        Ok(InputStatus::Modified) => "it was edited or corrupted",
+        _ => return,
+    };
+    eprintln!(
+        "warning: \"{}\" does not match the downloaded input, {}. Run `cargo download <day> --force` to restore it.",
+        path.display(),
+        reason
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("1000\n2000\n"), checksum("1000\r\n2000\r\n"));
+    }
+
+    #[test]
+    fn test_manifest() {
+        let mut manifest = Manifest::parse("af63dc4c8601ec8c  01.txt\n");
+        assert_eq!(manifest.get("01.txt"), Some("af63dc4c8601ec8c"));
+        assert_eq!(manifest.get("02.txt"), None);
+
+        manifest.insert("02.txt", checksum(""));
+        assert_eq!(
+            manifest.to_string(),
+            "af63dc4c8601ec8c  01.txt\ncbf29ce484222325  02.txt\n"
+        );
+        assert_eq!(Manifest::parse(&manifest.to_string()), manifest);
+    }
+
+    #[test]
+    fn test_input_status() {
+        let input = "1000\n2000\n\n3000\n";
+        let recorded = checksum(input);
+        assert_eq!(InputStatus::of(input, None), InputStatus::Unrecorded);
+        assert_eq!(
+            InputStatus::of(input, Some(&recorded)),
+            InputStatus::Unchanged
+        );
+        assert_eq!(
+            InputStatus::of(&input.replace('\n', "\r\n"), Some(&recorded)),
+            InputStatus::CrlfConverted
+        );
+        assert_eq!(
+            InputStatus::of("1000\n2001\n\n3000\n", Some(&recorded)),
+            InputStatus::Modified
+        );
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let dir = std::env::temp_dir().join(format!("aoc_cache_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("07.txt");
+
+        assert_eq!(verify(&path, "$ ls\n").unwrap(), InputStatus::Unrecorded);
+        record(&path, "$ ls\n").unwrap();
+        assert_eq!(verify(&path, "$ ls\n").unwrap(), InputStatus::Unchanged);
+        assert_eq!(verify(&path, "$ cd /\n").unwrap(), InputStatus::Modified);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stale_lock() {
+        let dir = std::env::temp_dir().join(format!("aoc_lock_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("08.txt");
+        let lock_path = dir.join(format!("{}.lock", MANIFEST_FILE));
+
+        // left behind by a download that crashed a minute ago.
+        let lock = fs::File::create(&lock_path).unwrap();
+        lock.set_modified(SystemTime::now() - Duration::from_secs(60))
+            .unwrap();
+        assert!(is_stale(&lock_path));
+
+        record(&path, "30373\n").unwrap();
+        assert!(!lock_path.exists());
+        assert_eq!(verify(&path, "30373\n").unwrap(), InputStatus::Unchanged);
+
+        // a fresh lock that replaced the stale one is left to its download.
+        fs::File::create(&lock_path).unwrap();
+        assert!(!is_stale(&lock_path));
+        remove_stale_lock(&lock_path).unwrap();
+        assert!(lock_path.exists());
+        assert!(!lock_path
+            .with_extension(format!("lock.{}", process::id()))
+            .exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
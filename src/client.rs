@@ -0,0 +1,499 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable that overrides [`DEFAULT_BASE_URL`], e.g. to point at a local stub server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Identifies this tool and its owner in requests, as asked for by the Advent of Code maintainers:
+/// the package name and version, followed by the `repository` of `Cargo.toml` if it is set.
+fn user_agent(repository: &str) -> String {
+    let tool = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    if repository.is_empty() {
+        tool.to_string()
+    } else {
+        format!("{} (+{})", tool, repository)
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `./.adventofcode.session` nor `~/.adventofcode.session` could be read.
+    NoSession(io::Error),
+    Request(ureq::Error),
+    /// The server rejected the session cookie, usually because it expired.
+    SessionExpired,
+    /// The puzzle exists but is not unlocked yet.
+    NotUnlocked {
+        year: u16,
+        day: u8,
+    },
+    /// There is no puzzle for this day and year.
+    NotFound {
+        year: u16,
+        day: u8,
+    },
+    /// The server answered with an unexpected status code.
+    Status(u16),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession(e) => write!(
+                f,
+                "could not read session cookie from \"{}\": {}",
+                SESSION_FILE, e
+            ),
+            ClientError::Request(e) => write!(f, "request failed: {}", e),
+            ClientError::SessionExpired => write!(
+                f,
+                "the session cookie in \"{}\" is invalid or expired, copy a new one from your browser",
+                SESSION_FILE
+            ),
+            ClientError::NotUnlocked { year, day } => {
+                write!(f, "day {} of {} is not unlocked yet", day, year)
+            }
+            ClientError::NotFound { year, day } => {
+                write!(f, "there is no puzzle for day {} of {}", day, year)
+            }
+            ClientError::Status(status) => write!(f, "server responded with status {}", status),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Request(e)
+    }
+}
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. `wait` is the remaining cooldown, if the server mentioned it.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// The response did not match any known message.
+    Unknown(String),
+}
+
+impl SubmitOutcome {
+    /// Classifies the HTML page returned for a submitted answer.
+    pub fn from_response(body: &str) -> Self {
+        let text = main_text(body);
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else {
+            SubmitOutcome::Unknown(text)
+        }
+    }
+
+    pub fn is_correct(&self) -> bool {
+        *self == SubmitOutcome::Correct
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::Wrong => write!(f, "That's not the right answer."),
+            SubmitOutcome::TooHigh => write!(f, "That's not the right answer: too high."),
+            SubmitOutcome::TooLow => write!(f, "That's not the right answer: too low."),
+            SubmitOutcome::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmitOutcome::RateLimited { wait: None } => {
+                write!(f, "You gave an answer too recently.")
+            }
+            SubmitOutcome::WrongLevel => {
+                write!(f, "This part is already solved or not unlocked yet.")
+            }
+            SubmitOutcome::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+/// Returns the text of the page's `<main>` element with all tags removed.
+fn main_text(body: &str) -> String {
+    let start = body.find("<main>").map_or(0, |i| i + "<main>".len());
+    let end = body[start..]
+        .find("</main>")
+        .map_or(body.len(), |i| start + i);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the cooldown from e.g. "You have 1m 5s left to wait." or "You have 38s left to wait.".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads the session cookie from `./.adventofcode.session`, falling back to `~/.adventofcode.session`.
+pub fn read_session() -> io::Result<String> {
+    let mut paths = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        paths.push(PathBuf::from(home).join(SESSION_FILE));
+    }
+
+    let mut error = io::Error::new(io::ErrorKind::NotFound, "no session file");
+    for path in paths {
+        match fs::read_to_string(&path) {
+            Ok(session) if !session.trim().is_empty() => return Ok(session.trim().to_string()),
+            Ok(_) => {
+                error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} is empty", path.display()),
+                )
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => error = e,
+        }
+    }
+    Err(error)
+}
+
+/// Minimal client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    pub year: u16,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(user_agent(env!("CARGO_PKG_REPOSITORY")))
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client with the session from [`read_session`].
+    /// `base_url` defaults to `$AOC_BASE_URL`, then [`DEFAULT_BASE_URL`].
+    pub fn from_env(base_url: Option<String>, year: u16) -> Result<Self, ClientError> {
+        let session = read_session().map_err(ClientError::NoSession)?;
+        let base_url = base_url
+            .or_else(|| env::var(BASE_URL_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session, year))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, self.year, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Returns the body of a successful response or the error it describes.
+    fn read_body(
+        &self,
+        day: u8,
+        mut response: ureq::http::Response<ureq::Body>,
+    ) -> Result<String, ClientError> {
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        match status {
+            200 => Ok(body),
+            // the server asks to log in when the cookie is missing, invalid or expired.
+            400 | 500 if body.contains("log in") => Err(ClientError::SessionExpired),
+            404 if body.contains("before it unlocks") => Err(ClientError::NotUnlocked {
+                year: self.year,
+                day,
+            }),
+            404 => Err(ClientError::NotFound {
+                year: self.year,
+                day,
+            }),
+            status => Err(ClientError::Status(status)),
+        }
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .header("Cookie", &self.cookie())
+            .call()?;
+        self.read_body(day, response)
+    }
+
+    /// Posts `answer` for a part of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<SubmitOutcome, ClientError> {
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .header("Cookie", &self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+        let body = self.read_body(day, response)?;
+        Ok(SubmitOutcome::from_response(&body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    /// Serves a single request with `body` and returns the URL to use and a handle yielding the raw request.
+    fn stub_server(status: u16, body: String) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_from_response() {
+        assert_eq!(
+            SubmitOutcome::from_response(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            SubmitOutcome::Wrong
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page("You gave an answer too recently.")),
+            SubmitOutcome::RateLimited { wait: None }
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(&page("Something <b>else</b>")),
+            SubmitOutcome::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = stub_server(200, page("That's the right answer!"));
+        let client = Client::new(&url, "abc123", 2022);
+
+        let outcome = client.submit(5, 2, "MCD").unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request
+            .to_ascii_lowercase()
+            .contains("\r\ncookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=MCD"));
+    }
+
+    #[test]
+    fn test_submit_status() {
+        let (url, server) = stub_server(500, String::new());
+        let client = Client::new(&url, "abc123", 2022);
+
+        assert!(matches!(
+            client.submit(5, 1, "CMZ"),
+            Err(ClientError::Status(500))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = stub_server(200, "2-4,6-8\n2-3,4-5\n".to_string());
+        let client = Client::new(&url, "abc123", 2021);
+
+        assert_eq!(client.input(4).unwrap(), "2-4,6-8\n2-3,4-5\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/day/4/input HTTP/1.1\r\n"));
+        assert!(request
+            .to_ascii_lowercase()
+            .contains("\r\ncookie: session=abc123\r\n"));
+        assert!(request.contains(&format!(
+            "\r\nuser-agent: {}\r\n",
+            user_agent(env!("CARGO_PKG_REPOSITORY"))
+        )));
+    }
+
+    #[test]
+    fn test_user_agent() {
+        assert_eq!(
+            user_agent("https://example.com/aoc"),
+            format!(
+                "advent_of_code/{} (+https://example.com/aoc)",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert!(!user_agent("").contains("fspoettel"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let cases = [
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.",
+            ),
+            (404, "404 Not Found"),
+            (503, "Service Unavailable"),
+        ];
+        let mut errors = vec![];
+        for (status, body) in cases {
+            let (url, server) = stub_server(status, body.to_string());
+            errors.push(Client::new(&url, "expired", 2022).input(25).unwrap_err());
+            server.join().unwrap();
+        }
+
+        assert!(matches!(errors[0], ClientError::SessionExpired));
+        assert!(matches!(
+            errors[1],
+            ClientError::NotUnlocked {
+                year: 2022,
+                day: 25
+            }
+        ));
+        assert!(matches!(
+            errors[2],
+            ClientError::NotFound {
+                year: 2022,
+                day: 25
+            }
+        ));
+        assert!(matches!(errors[3], ClientError::Status(503)));
+        assert_eq!(errors[1].to_string(), "day 25 of 2022 is not unlocked yet");
+    }
+}
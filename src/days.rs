@@ -0,0 +1,26 @@
+//! The solutions of every day. `src/bin/NN.rs` solves a single day, the `all` runner solves all of them.
+//! Days of other years than [`crate::DEFAULT_YEAR`] live in a module per year, e.g. `y2021::day07`.
+
+// The days solved before the move into the library are kept as they were written, warnings included.
+#[allow(warnings, clippy::all)]
+pub mod day01;
+#[allow(warnings, clippy::all)]
+pub mod day02;
+#[allow(warnings, clippy::all)]
+pub mod day03;
+#[allow(warnings, clippy::all)]
+pub mod day04;
+#[allow(warnings, clippy::all)]
+pub mod day05;
+#[allow(warnings, clippy::all)]
+pub mod day06;
+#[allow(warnings, clippy::all)]
+pub mod day07;
+#[allow(warnings, clippy::all)]
+pub mod day08;
+#[allow(warnings, clippy::all)]
+pub mod day09;
+#[allow(warnings, clippy::all)]
+pub mod day10;
+#[allow(warnings, clippy::all)]
+pub mod day11;
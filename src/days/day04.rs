@@ -0,0 +1,112 @@
+/*
+Approach
+- We need to find for each pair of numbers in the input, what are the numbers included in the range
+- Then we will compare them with the inputs in the other pairs split by a comma
+- We want to figure out which pairs fully include the other pairs
+E.g. 2-4 is fully included by 1-5 or by 2-4.
+We will count those pairs and return the count
+
+Methods
+- We need to use one method to split the input by commas into a vector of strings
+- Then for each pair in that list, we need to create a range of numbers 
+that includes the start and end of the range.
+- We need to compare if the start and end is contained by the other ranges
+If so we will count those.
+
+E.g., 2-4, 1-5
+
+In this example, we will store the ranges in a vector of tuples
+[(2,4), (1,5)]
+
+We will then compare the first range with the second range
+If the start of the first range is greater than or equal to the start of the second range
+we will check if the end of the first range is less than or equal to the end of the second range
+
+If both conditions are true, we will count the pair
+*/
+
+use std::collections::HashSet;
+
+use crate::error::{parse_lines, ParseError};
+
+struct Range {
+    start: i32,
+    end: i32,
+}
+
+impl Range {
+    fn contains (&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlap (&self, other: &Range) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+}
+
+fn string_into_range(input: &str) -> Option<Range> {
+    let (start, end) = input.split_once('-')?;
+    let start = start.parse::<i32>().ok()?;
+    let end = end.parse::<i32>().ok()?;
+    Some(Range { start, end })
+}
+
+fn parse_assignments(line: &str) -> Option<Vec<Range>> {
+    line.split(',').map(string_into_range).collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, ParseError> {
+    let _ranges: Vec<Vec<Range>> = parse_lines(input, parse_assignments)?;
+    Ok(None)
+}
+
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let mut overlap_count = 0;
+    for assignment_ranges in parse_lines(input, parse_assignments)? {
+        
+        let mut overlapping_shifts_indices = HashSet::new();
+
+        for (i, range_iter) in assignment_ranges.iter().enumerate() {
+            for (j, range2_iter) in assignment_ranges.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                if range_iter.start <= range2_iter.end && range_iter.end >= range2_iter.start {
+                    let mut indices = vec![i, j];
+                    indices.sort();
+                    if !overlapping_shifts_indices.contains(&indices) {
+                        overlapping_shifts_indices.insert(indices);
+                        overlap_count += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(overlap_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_two(&input), Ok(4));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            part_two("2-4,6-8\n2-x,4-5"),
+            Err(ParseError::new(2, "2-x,4-5"))
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::helpers::grid::{Grid, CARDINAL_DIRECTIONS};
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::parse_digits(input).ok()?;
+    let visible = grid
+        .positions()
+        .filter(|&tree| grid.visible_from_edges(tree))
+        .count();
+    Some(visible as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::parse_digits(input).ok()?;
+    grid.positions()
+        .map(|tree| {
+            CARDINAL_DIRECTIONS
+                .iter()
+                .map(|&direction| grid.viewing_distance(tree, direction) as u32)
+                .product()
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
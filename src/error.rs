@@ -0,0 +1,66 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error::Error;
+use std::fmt;
+
+/// Error for input lines a solution could not parse.
+/// Line numbers start at 1, like in an editor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: &str) -> Self {
+        ParseError {
+            line,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "parse error on line {}: `{}`", self.line, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of `input` with `parse`, reporting the first line it rejects.
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).ok_or_else(|| ParseError::new(i + 1, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::new(37, "move x from 1 to 2").to_string(),
+            "parse error on line 37: `move x from 1 to 2`"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| line.parse::<u32>().ok();
+        assert_eq!(parse_lines("1\n2\n3", parse), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_lines("1\ntwo\n3", parse),
+            Err(ParseError::new(2, "two"))
+        );
+    }
+}
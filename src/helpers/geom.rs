@@ -0,0 +1,259 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A position or an offset in the plane. Like in [`Grid`](super::grid::Grid), `y` grows downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scales both coordinates, e.g. `Direction::Up.unit() * 3` is three steps up.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The signed integer types that distances and signs of points are computed for.
+pub trait Signed: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty)*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8 i16 i32 i64 i128 isize);
+
+impl<T: Signed> Point2<T> {
+    /// The number of horizontal and vertical steps between the points.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of steps between the points when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The sign of both coordinates, e.g. the single step that moves a rope's tail towards its head.
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// The offset of one step in this direction, up is towards smaller `y`.
+    pub fn unit(self) -> Point2<i32> {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+            Direction::Right => Point2::new(1, 0),
+        }
+    }
+}
+
+/// Lets a direction be passed where an offset is expected, e.g. to [`Grid::ray`](super::grid::Grid::ray).
+impl From<Direction> for (i32, i32) {
+    fn from(direction: Direction) -> Self {
+        direction.unit().into()
+    }
+}
+
+/// Why a [`Direction`] could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    pub text: String,
+}
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid direction `{}`, expected U, D, L or R",
+            self.text
+        )
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Parses the `U`, `D`, `L` and `R` of puzzle inputs like the rope moves of 2022 day 9.
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseDirectionError {
+                text: text.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let mut head = Point2::new(1, 2);
+        head += Direction::Right.unit() * 3;
+        assert_eq!(head, Point2::new(4, 2));
+        assert_eq!(head - Point2::new(1, 1), Point2::new(3, 1));
+        head -= Point2::from((4, 4));
+        assert_eq!(<(i32, i32)>::from(head), (0, -2));
+        assert_eq!(
+            Point2::new(1.5, 2.0) + Point2::new(0.5, 1.0),
+            Point2::new(2.0, 3.0)
+        );
+        assert_eq!(Point2::new(-3i64, 7).to_string(), "(-3, 7)");
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(0, 0);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Point2::new(0i64, 5).signum(), Point2::new(0, 1));
+    }
+
+    #[test]
+    fn test_direction() {
+        let moves: Result<Vec<Direction>, _> = "L U R D".split(' ').map(str::parse).collect();
+        assert_eq!(moves.unwrap(), Direction::ALL.map(Direction::turn_left));
+        assert_eq!(
+            "X".parse::<Direction>().unwrap_err().to_string(),
+            "invalid direction `X`, expected U, D, L or R"
+        );
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.unit() + direction.opposite().unit(),
+                Point2::new(0, 0)
+            );
+        }
+        assert_eq!(<(i32, i32)>::from(Direction::Up), (0, -1));
+    }
+}
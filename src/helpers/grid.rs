@@ -0,0 +1,770 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub const CARDINAL_DIRECTIONS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+pub const ALL_DIRECTIONS: [(i32, i32); 8] = [
+    (0, 1),
+    (0, -1),
+    (1, 0),
+    (-1, 0),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+pub const DIAGONAL_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Iterates over the positions next to a position of a [`Grid`], created by [`Grid::neighbors`],
+/// [`Grid::diagonal_neighbors`] and [`Grid::all_neighbors`].
+/// Positions outside of the grid are skipped, unless the grid [wraps around](Neighbors::wrapping).
+#[derive(Clone, Debug)]
+pub struct Neighbors {
+    position: (usize, usize),
+    size: (usize, usize),
+    offsets: &'static [(i32, i32)],
+    next: usize,
+    wrapping: bool,
+    include_self: bool,
+}
+
+impl Neighbors {
+    fn new(position: (usize, usize), size: (usize, usize), offsets: &'static [(i32, i32)]) -> Self {
+        Neighbors {
+            position,
+            size,
+            offsets,
+            next: 0,
+            wrapping: false,
+            include_self: false,
+        }
+    }
+
+    /// Treats the grid as a torus: the neighbors past an edge are on the opposite edge.
+    /// In a grid narrower than 3 cells, a position can be yielded more than once.
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    /// Yields the position itself before its neighbors.
+    pub fn with_self(mut self) -> Self {
+        self.include_self = true;
+        self
+    }
+
+    /// `coordinate + offset` inside `0..size`, or `None` if it is outside and the grid does not wrap.
+    fn step(&self, coordinate: usize, offset: i32, size: usize) -> Option<usize> {
+        if self.wrapping {
+            let wrapped = (coordinate as i64 + offset as i64).rem_euclid(size as i64);
+            Some(wrapped as usize)
+        } else {
+            coordinate
+                .checked_add_signed(offset as isize)
+                .filter(|&moved| moved < size)
+        }
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.include_self {
+            self.include_self = false;
+            return Some(self.position);
+        }
+        while let Some(&(dx, dy)) = self.offsets.get(self.next) {
+            self.next += 1;
+            let (x, y) = self.position;
+            let (width, height) = self.size;
+            if let (Some(x), Some(y)) = (self.step(x, dx, width), self.step(y, dy, height)) {
+                return Some((x, y));
+            }
+        }
+        None
+    }
+}
+
+/// Iterates over the cells in a line, created by [`Grid::ray`].
+#[derive(Clone, Debug)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    position: (usize, usize),
+    direction: (i32, i32),
+}
+
+impl<'a, T> Ray<'a, T> {
+    /// The position of the cell yielded last, `from` before the first step.
+    pub fn position(&self) -> (usize, usize) {
+        self.position
+    }
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (x, y) = self.position;
+        let (dx, dy) = self.direction;
+        let x = x.checked_add_signed(dx as isize)?;
+        let y = y.checked_add_signed(dy as isize)?;
+        let cell = self.grid.get(x, y)?;
+        self.position = (x, y);
+        Some(cell)
+    }
+}
+
+/// Why an input could not be parsed into a [`Grid`]. Lines and columns start at 1, like in an editor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A line is longer or shorter than the first one.
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character the cell parser rejected, e.g. a letter in a grid of digits.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} cells, expected {} like the first line",
+                line, found, expected
+            ),
+            GridError::InvalidCell {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "invalid cell `{}` on line {}, column {}",
+                found, line, column
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+/// A rectangular grid of cells, stored row by row in one `Vec`.
+/// Positions are `(x, y)`, where the top-left position is `(0, 0)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T = char> {
+    cells: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// The grid of most puzzle inputs, one `char` per cell. `Grid` without a type parameter is the same type.
+pub type CharGrid = Grid<char>;
+
+impl Grid<char> {
+    /// Parses every character of `input` into a cell.
+    pub fn parse_chars(input: &str) -> Result<Self, GridError> {
+        Grid::parse_with(input, |c| c)
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of decimal digits, e.g. the tree heights of 2022 day 8.
+    pub fn parse_digits(input: &str) -> Result<Self, GridError> {
+        Grid::try_parse_with(input, |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses every character of `input` into a cell with `parse`.
+    pub fn parse_with(input: &str, mut parse: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Grid::try_parse_with(input, |c| Some(parse(c)))
+    }
+
+    /// Like [`Grid::parse_with`], but `parse` may reject a character by returning `None`.
+    pub fn try_parse_with(
+        input: &str,
+        mut parse: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let cell = parse(c).ok_or(GridError::InvalidCell {
+                    line: y + 1,
+                    column: x + 1,
+                    found: c,
+                })?;
+                cells.push(cell);
+            }
+
+            let found = cells.len() - row_start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::RaggedRow {
+                        line: y + 1,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Sets the cell at `(x, y)`. Panics if the position is outside of the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self[(x, y)] = value;
+    }
+
+    /// Iterates over all cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates over the rows of the grid as slices.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Applies `f` to every cell, e.g. to turn a grid of chars into a grid of numbers.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The up to 4 positions above, below, left and right of `(x, y)`.
+    pub fn neighbors(&self, x: usize, y: usize) -> Neighbors {
+        Neighbors::new((x, y), (self.width, self.height), &CARDINAL_DIRECTIONS)
+    }
+
+    /// The up to 4 positions diagonally adjacent to `(x, y)`.
+    pub fn diagonal_neighbors(&self, x: usize, y: usize) -> Neighbors {
+        Neighbors::new((x, y), (self.width, self.height), &DIAGONAL_DIRECTIONS)
+    }
+
+    /// The up to 8 positions around `(x, y)`, cardinal ones first.
+    pub fn all_neighbors(&self, x: usize, y: usize) -> Neighbors {
+        Neighbors::new((x, y), (self.width, self.height), &ALL_DIRECTIONS)
+    }
+
+    pub fn get_adjacent_positions(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.neighbors(x, y).collect()
+    }
+
+    pub fn get_all_positions(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.all_neighbors(x, y).collect()
+    }
+
+    /// Iterates over the cells in a straight line from `from` to the edge of the grid, without the cell at `from`.
+    /// `direction` is the offset of a step, e.g. one of [`CARDINAL_DIRECTIONS`] or a [`Direction`](super::geom::Direction).
+    /// Panics if it is `(0, 0)`.
+    pub fn ray(&self, from: (usize, usize), direction: impl Into<(i32, i32)>) -> Ray<'_, T> {
+        let direction = direction.into();
+        assert_ne!(direction, (0, 0), "a ray needs a direction to move in");
+        Ray {
+            grid: self,
+            position: from,
+            direction,
+        }
+    }
+
+    /// Whether the cell at `from` is greater than all cells between it and at least one edge of the grid,
+    /// looking up, down, left and right. Cells on the edge are always visible.
+    pub fn visible_from_edges(&self, from: (usize, usize)) -> bool
+    where
+        T: PartialOrd,
+    {
+        let cell = &self[from];
+        CARDINAL_DIRECTIONS
+            .iter()
+            .any(|&direction| self.ray(from, direction).all(|other| other < cell))
+    }
+
+    /// How many cells can be seen from `from` in `direction`: all cells up to and including
+    /// the first one that is at least as great as the cell at `from`, or up to the edge.
+    pub fn viewing_distance(&self, from: (usize, usize), direction: impl Into<(i32, i32)>) -> usize
+    where
+        T: PartialOrd,
+    {
+        let cell = &self[from];
+        let mut distance = 0;
+        for other in self.ray(from, direction) {
+            distance += 1;
+            if other >= cell {
+                break;
+            }
+        }
+        distance
+    }
+
+    pub fn iterate_through_grid(&self) -> Vec<(usize, usize)> {
+        self.positions().collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "position ({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index_of(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "position ({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Prints one line per row, without separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The smallest rectangle that contains all cells of a [`SparseGrid`], both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: (i32, i32),
+    pub max: (i32, i32),
+}
+
+impl Bounds {
+    fn around(position: (i32, i32)) -> Self {
+        Bounds {
+            min: position,
+            max: position,
+        }
+    }
+
+    fn extend(&mut self, (x, y): (i32, i32)) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    fn is_on_edge(&self, (x, y): (i32, i32)) -> bool {
+        x == self.min.0 || x == self.max.0 || y == self.min.1 || y == self.max.1
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1) as usize + 1
+    }
+
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+
+    /// Iterates over all positions in the rectangle, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let (min_x, max_x) = (self.min.0, self.max.0);
+        (self.min.1..=self.max.1).flat_map(move |y| (min_x..=max_x).map(move |x| (x, y)))
+    }
+}
+
+/// A grid without edges, e.g. for sand falling onto a floor or elves spreading out over a plane.
+/// Only the cells that were set are stored, positions are `(x, y)` and may be negative.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i32, i32), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The rectangle around all cells, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, position: (i32, i32)) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: (i32, i32)) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: (i32, i32)) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    /// Sets the cell at `position` and returns its previous value.
+    pub fn insert(&mut self, position: (i32, i32), value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(position),
+            None => self.bounds = Some(Bounds::around(position)),
+        }
+        self.cells.insert(position, value)
+    }
+
+    /// Removes the cell at `position` and returns its value. The bounds shrink if it was on their edge.
+    pub fn remove(&mut self, position: (i32, i32)) -> Option<T> {
+        let value = self.cells.remove(&position)?;
+        if self
+            .bounds
+            .is_some_and(|bounds| bounds.is_on_edge(position))
+        {
+            let mut positions = self.cells.keys().copied();
+            self.bounds = positions.next().map(|first| {
+                let mut bounds = Bounds::around(first);
+                positions.for_each(|position| bounds.extend(position));
+                bounds
+            });
+        }
+        Some(value)
+    }
+
+    /// Iterates over the cells that were set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().map(|(&position, cell)| (position, cell))
+    }
+
+    /// The cells that were set above, below, left and right of `position`.
+    pub fn neighbors(&self, position: (i32, i32)) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbors_at(position, &CARDINAL_DIRECTIONS)
+    }
+
+    /// The cells that were set among the 8 positions around `position`.
+    pub fn all_neighbors(&self, position: (i32, i32)) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbors_at(position, &ALL_DIRECTIONS)
+    }
+
+    fn neighbors_at<'a>(
+        &'a self,
+        (x, y): (i32, i32),
+        offsets: &'static [(i32, i32)],
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let position = (x + dx, y + dy);
+            self.get(position).map(|cell| (position, cell))
+        })
+    }
+
+    /// Iterates over all positions within the bounds, row by row, with the cell if it was set.
+    pub fn iter_bounds(&self) -> impl Iterator<Item = ((i32, i32), Option<&T>)> {
+        self.bounds
+            .into_iter()
+            .flat_map(|bounds| bounds.positions())
+            .map(|position| (position, self.get(position)))
+    }
+
+    /// Copies the cells within the bounds into a [`Grid`], `render` maps every position to a cell.
+    /// The top-left corner of the bounds becomes `(0, 0)`.
+    /// For display, e.g. `sparse.to_grid(|cell| if cell.is_some() { '#' } else { '.' })`.
+    pub fn to_grid<U>(&self, mut render: impl FnMut(Option<&T>) -> U) -> Grid<U> {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => {
+                return Grid {
+                    cells: vec![],
+                    width: 0,
+                    height: 0,
+                }
+            }
+        };
+        Grid {
+            cells: self.iter_bounds().map(|(_, cell)| render(cell)).collect(),
+            width: bounds.width(),
+            height: bounds.height(),
+        }
+    }
+}
+
+impl<T> FromIterator<((i32, i32), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i32, i32), T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (position, value) in iter {
+            grid.insert(position, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::geom::Direction;
+
+    const TREES: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    #[test]
+    fn test_parse_chars() {
+        let grid = Grid::parse_chars("#..\n.#.\n").unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.get(1, 1), Some(&'#'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits(TREES).unwrap();
+        assert_eq!((grid.width, grid.height), (5, 5));
+        assert_eq!(grid[(3, 0)], 7);
+        assert_eq!(grid[(4, 3)], 9);
+        assert_eq!(grid.to_string(), TREES.trim_end());
+        assert_eq!(
+            Grid::parse_digits("123\n4x6"),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("#.\n.#", |c| c == '#').unwrap();
+        assert_eq!(grid.iter().filter(|&&wall| wall).count(), 2);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![[true, false], [false, true]]
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let error = Grid::parse_chars("abc\nab\nabc").unwrap_err();
+        assert_eq!(
+            error,
+            GridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2 has 2 cells, expected 3 like the first line"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid<u8> = Grid::new(3, 3, 0);
+        let mut corner: Vec<_> = grid.neighbors(0, 0).collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors(1, 1).count(), 4);
+        assert_eq!(
+            grid.diagonal_neighbors(2, 0).collect::<Vec<_>>(),
+            vec![(1, 1)]
+        );
+        assert_eq!(grid.all_neighbors(1, 1).count(), 8);
+        assert_eq!(grid.all_neighbors(0, 2).count(), 3);
+        assert_eq!(grid.get_all_positions(2, 2).len(), 3);
+        assert_eq!(grid.neighbors(1, 0).with_self().next(), Some((1, 0)));
+        assert_eq!(grid.all_neighbors(0, 0).with_self().count(), 4);
+    }
+
+    #[test]
+    fn test_neighbors_wrapping() {
+        let grid: Grid<u8> = Grid::new(4, 3, 0);
+        let mut corner: Vec<_> = grid.neighbors(0, 0).wrapping().collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (0, 2), (1, 0), (3, 0)]);
+        assert!(grid
+            .all_neighbors(3, 2)
+            .wrapping()
+            .any(|position| position == (0, 0)));
+        assert_eq!(grid.all_neighbors(0, 0).wrapping().with_self().count(), 9);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse_digits(TREES).unwrap();
+        let up: Vec<_> = grid.ray((2, 3), (0, -1)).copied().collect();
+        assert_eq!(up, vec![3, 5, 3]);
+        assert_eq!(grid.ray((4, 4), (1, 1)).count(), 0);
+
+        let mut diagonal = grid.ray((0, 0), (1, 1));
+        assert_eq!(diagonal.nth(2), Some(&4));
+        assert_eq!(diagonal.position(), (3, 3));
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let grid = Grid::parse_digits(TREES).unwrap();
+        assert!(grid.visible_from_edges((0, 2)));
+        assert!(grid.visible_from_edges((1, 1)));
+        assert!(!grid.visible_from_edges((2, 2)));
+        let visible = grid
+            .positions()
+            .filter(|&position| grid.visible_from_edges(position))
+            .count();
+        assert_eq!(visible, 21);
+
+        let distances: Vec<_> = CARDINAL_DIRECTIONS
+            .iter()
+            .map(|&direction| grid.viewing_distance((2, 3), direction))
+            .collect();
+        assert_eq!(distances, vec![1, 2, 2, 2]);
+        assert_eq!(grid.viewing_distance((0, 0), (-1, 0)), 0);
+        assert_eq!(grid.viewing_distance((2, 3), Direction::Up), 2);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut sparse = SparseGrid::new();
+        assert_eq!(sparse.bounds(), None);
+        assert_eq!(sparse.to_grid(|cell| cell.copied()).height, 0);
+
+        sparse.insert((0, 0), 'H');
+        sparse.insert((-2, 1), 'T');
+        assert_eq!(sparse.insert((0, 0), 's'), Some('H'));
+        assert_eq!(
+            sparse.bounds(),
+            Some(Bounds {
+                min: (-2, 0),
+                max: (0, 1)
+            })
+        );
+        assert_eq!(
+            sparse.neighbors((-1, 1)).collect::<Vec<_>>(),
+            vec![((-2, 1), &'T')]
+        );
+        assert_eq!(sparse.all_neighbors((-1, 0)).count(), 2);
+        assert_eq!(
+            sparse
+                .iter_bounds()
+                .filter(|(_, cell)| cell.is_none())
+                .count(),
+            4
+        );
+
+        let grid = sparse.to_grid(|cell| *cell.unwrap_or(&'.'));
+        assert_eq!(grid.to_string(), "..s\nT..");
+
+        assert_eq!(sparse.remove((-2, 1)), Some('T'));
+        assert_eq!(sparse.bounds().map(|bounds| bounds.width()), Some(1));
+        sparse.remove((0, 0));
+        assert!(sparse.is_empty());
+        assert_eq!(sparse.bounds(), None);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let sparse: SparseGrid<()> = [((3, -1), ()), ((1, 2), ())].into_iter().collect();
+        let bounds = sparse.bounds().unwrap();
+        assert_eq!((bounds.width(), bounds.height()), (3, 4));
+        assert!(bounds.contains((2, 0)));
+        assert!(!bounds.contains((0, 0)));
+        assert_eq!(bounds.positions().count(), 12);
+        assert_eq!(bounds.positions().next(), Some((1, -1)));
+    }
+
+    #[test]
+    fn test_set_and_map() {
+        let mut grid: Grid<u8> = Grid::new(3, 2, 0);
+        grid.set(2, 1, 5);
+        grid[(0, 0)] += 1;
+        assert_eq!(grid.to_string(), "100\n005");
+        assert_eq!(
+            grid.positions().filter(|&(x, y)| grid[(x, y)] > 0).count(),
+            2
+        );
+        assert_eq!(grid.map(|&height| height > 2).get(2, 1), Some(&true));
+        assert_eq!(Grid::parse_chars("").unwrap().rows().count(), 0);
+    }
+}
@@ -5,49 +5,177 @@
  */
 use std::env;
 use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
 
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod days;
+pub mod error;
 pub mod helpers;
+pub mod lint;
+pub mod memory;
+pub mod options;
+pub mod puzzle;
+pub mod readme_benchmarks;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod template;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Solves one part, prints the result in the format chosen with `--format` and evaluates to its [`report::PartResult`].
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+        use advent_of_code::options::Options;
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        let options = Options::from_env();
+        let (year, day) = advent_of_code::parse_bin_name(env!("CARGO_BIN_NAME"))
+            .unwrap_or((advent_of_code::DEFAULT_YEAR, 0));
+        let solver = |input: &str| advent_of_code::registry::run($solver, input);
+        let result = advent_of_code::solve_part(year, day, $part, solver, $input, options);
+        result.print(options.format);
+        // parts are solved in order, so part 2 is the last chance to report a failed check.
+        if options.check && $part == 2 && advent_of_code::answers::check_failed() {
+            std::process::exit(1);
+        }
+        result
     }};
 }
 
+/// Runs `solver` once, or benchmarks it if `--bench` was passed.
+/// With `--check`, the answer is also compared with the accepted answer.
+/// With `--format json`, anything the solver prints goes to stderr, see [`report::reserve_stdout_for_records`].
+pub fn solve_part(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> registry::Run,
+    input: &str,
+    options: &options::Options,
+) -> report::PartResult {
+    if options.format == report::Format::Json {
+        report::reserve_stdout_for_records();
+    }
+    let mut result = if options.bench {
+        let (run, stats) = bench::bench(solver, input);
+        report::PartResult::new(day, part, run).with_stats(stats)
+    } else {
+        report::PartResult::new(day, part, solver(input))
+    };
+    if options.check {
+        result.check = Some(answers::check(year, &result));
+    }
+    result
+}
+
+/// The year of the puzzles in `src/bin/NN.rs`. Days of other years live in `src/bin/YYYY-NN.rs`.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Environment variable that points to a directory to read puzzle inputs from instead of `src/inputs`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name of the binary that solves `day` of `year`, e.g. `"07"` or `"2021-07"`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}-{:02}", year, day)
+    }
+}
+
+/// Path of the module that solves `day` of `year` below [`days`], e.g. `"day07"` or `"y2021::day07"`.
+pub fn day_module(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{:02}", day)
+    } else {
+        format!("y{}::day{:02}", year, day)
+    }
+}
+
+/// Parses the year and day from a day binary's name, see [`bin_name`].
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    match name.split_once('-') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((DEFAULT_YEAR, name.parse().ok()?)),
+    }
+}
+
+/// Reads `src/<folder>/<DEFAULT_YEAR>/<day>.txt`, see [`read_year_file`].
 pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+    read_year_file(folder, DEFAULT_YEAR, day)
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Reads `src/<folder>/<year>/<day>.txt`. Puzzle inputs are read from `$AOC_INPUT_DIR/<year>` if it is set.
+pub fn read_year_file(folder: &str, year: u16, day: u8) -> String {
+    let f = try_read_file(folder, year, day);
+    f.unwrap_or_else(|e| panic!("could not open input file: {}", e))
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads the puzzle input of `day` from `source`, or from `src/inputs/<year>/<day>.txt` if there is none.
+/// Day binaries get `source` from `--input`, see [`read_input!`].
+pub fn read_input_from(year: u16, day: u8, source: Option<&options::InputSource>) -> String {
+    let f = match source {
+        Some(options::InputSource::Stdin) => read_stdin(),
+        Some(options::InputSource::Path(path)) => fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        None => try_read_file("inputs", year, day),
+    };
+    f.unwrap_or_else(|e| panic!("could not open input file: {}", e))
+}
+
+/// Reads the puzzle input of a day binary: from `--input <path>`, from stdin with `--input -`,
+/// or from `src/inputs/<year>/<day>.txt`. The year defaults to [`DEFAULT_YEAR`].
+#[macro_export]
+macro_rules! read_input {
+    ($day:expr) => {
+        advent_of_code::read_input!(advent_of_code::DEFAULT_YEAR, $day)
+    };
+    ($year:expr, $day:expr) => {{
+        let options = advent_of_code::options::Options::from_env();
+        advent_of_code::read_input_from($year, $day, options.input.as_ref())
+    }};
+}
+
+/// Reads `src/<folder>/<year>/<day>.txt`. Puzzle inputs are read from `$AOC_INPUT_DIR/<year>` if it is set,
+/// and a warning is printed if they no longer match their downloaded version.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> io::Result<String> {
+    let filepath = file_path(folder, year, day)?;
+    let contents = fs::read_to_string(&filepath)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", filepath.display(), e)))?;
+    if folder == "inputs" {
+        cache::warn_if_modified(&filepath, &contents);
+    }
+    Ok(contents)
+}
+
+pub fn file_path(folder: &str, year: u16, day: u8) -> io::Result<PathBuf> {
+    let filename = format!("{:02}.txt", day);
+
+    if folder == "inputs" {
+        if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+            return Ok(PathBuf::from(dir).join(year.to_string()).join(filename));
+        }
+    }
+
+    let cwd = env::current_dir()?;
+    Ok(cwd
+        .join("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(filename))
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
@@ -95,6 +223,38 @@ macro_rules! assert_approx_eq {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_file_path() {
+        let cwd = env::current_dir().unwrap();
+        assert_eq!(
+            file_path("examples", 2022, 7).unwrap(),
+            cwd.join("src").join("examples").join("2022").join("07.txt")
+        );
+
+        env::set_var(INPUT_DIR_VAR, "/tmp/teammate");
+        assert_eq!(
+            file_path("inputs", 2021, 7).unwrap(),
+            PathBuf::from("/tmp/teammate/2021/07.txt")
+        );
+        assert_eq!(
+            file_path("examples", 2021, 7).unwrap(),
+            cwd.join("src").join("examples").join("2021").join("07.txt")
+        );
+        env::remove_var(INPUT_DIR_VAR);
+    }
+
+    #[test]
+    fn test_bin_name() {
+        assert_eq!(bin_name(DEFAULT_YEAR, 7), "07");
+        assert_eq!(bin_name(2021, 7), "2021-07");
+        assert_eq!(day_module(DEFAULT_YEAR, 7), "day07");
+        assert_eq!(day_module(2021, 7), "y2021::day07");
+        assert_eq!(parse_bin_name("07"), Some((DEFAULT_YEAR, 7)));
+        assert_eq!(parse_bin_name("2021-25"), Some((2021, 25)));
+        assert_eq!(parse_bin_name("scaffold"), None);
+        assert_eq!(parse_bin_name("2021-x"), None);
+    }
+
     #[test]
     fn test_parse_exec_time() {
         assert_approx_eq!(
@@ -0,0 +1,256 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+const BOM: char = '\u{feff}';
+
+/// A formatting difference from the inputs served by Advent of Code, which parsers may trip over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Issue {
+    /// The input starts with a UTF-8 byte order mark.
+    Bom,
+    /// `lines` lines end with `\r\n` instead of `\n`.
+    Crlf {
+        lines: usize,
+    },
+    MissingTrailingNewline,
+    /// The last line is followed by `extra` blank lines.
+    ExtraTrailingNewlines {
+        extra: usize,
+    },
+    Tabs {
+        first_line: usize,
+        lines: usize,
+    },
+    /// `count` characters are not ASCII, the first one at `line`:`column`.
+    NonAscii {
+        line: usize,
+        column: usize,
+        count: usize,
+    },
+    /// The input is not valid UTF-8 from `line`:`column` on, so solutions can't read it.
+    InvalidUtf8 {
+        line: usize,
+        column: usize,
+    },
+}
+
+impl Issue {
+    /// Whether [`normalize`] fixes the issue. Tabs and non-ASCII characters may be part of the puzzle and are kept,
+    /// an input that is not UTF-8 has to be downloaded again.
+    pub fn is_fixable(&self) -> bool {
+        !matches!(
+            self,
+            Issue::Tabs { .. } | Issue::NonAscii { .. } | Issue::InvalidUtf8 { .. }
+        )
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Issue::Bom => write!(f, "starts with a byte order mark"),
+            Issue::Crlf { lines } => write!(f, "{} lines end with CRLF", lines),
+            Issue::MissingTrailingNewline => write!(f, "the last line has no trailing newline"),
+            Issue::ExtraTrailingNewlines { extra } => {
+                write!(f, "{} blank lines after the last line", extra)
+            }
+            Issue::Tabs { first_line, lines } => {
+                write!(
+                    f,
+                    "{} lines contain tabs, first on line {}",
+                    lines, first_line
+                )
+            }
+            Issue::NonAscii {
+                line,
+                column,
+                count,
+            } => write!(
+                f,
+                "{} non-ASCII characters, first on line {}, column {}",
+                count, line, column
+            ),
+            Issue::InvalidUtf8 { line, column } => write!(
+                f,
+                "not valid UTF-8 from line {}, column {} on",
+                line, column
+            ),
+        }
+    }
+}
+
+/// Lists the issues of the raw bytes of an input. An empty input has none.
+/// Bytes that are not valid UTF-8 are reported and also counted as non-ASCII characters.
+pub fn lint(bytes: &[u8]) -> Vec<Issue> {
+    let mut issues = lint_text(&String::from_utf8_lossy(bytes));
+    if let Err(e) = std::str::from_utf8(bytes) {
+        // the part before the error is valid, so it can be decoded to find the column.
+        let valid = std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+        let last_line = valid.rsplit('\n').next().unwrap_or_default();
+        issues.push(Issue::InvalidUtf8 {
+            line: valid.matches('\n').count() + 1,
+            column: last_line.chars().count() + 1,
+        });
+    }
+    issues
+}
+
+fn lint_text(contents: &str) -> Vec<Issue> {
+    let mut issues = vec![];
+    if contents.is_empty() {
+        return issues;
+    }
+
+    let body = match contents.strip_prefix(BOM) {
+        Some(body) => {
+            issues.push(Issue::Bom);
+            body
+        }
+        None => contents,
+    };
+
+    let crlf_lines = body.matches("\r\n").count();
+    if crlf_lines > 0 {
+        issues.push(Issue::Crlf { lines: crlf_lines });
+    }
+
+    let unix = body.replace("\r\n", "\n");
+    if !unix.ends_with('\n') {
+        issues.push(Issue::MissingTrailingNewline);
+    } else {
+        let extra = unix.len() - unix.trim_end_matches('\n').len() - 1;
+        if extra > 0 {
+            issues.push(Issue::ExtraTrailingNewlines { extra });
+        }
+    }
+
+    let tab_lines: Vec<usize> = unix
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains('\t'))
+        .map(|(index, _)| index + 1)
+        .collect();
+    if let Some(&first_line) = tab_lines.first() {
+        issues.push(Issue::Tabs {
+            first_line,
+            lines: tab_lines.len(),
+        });
+    }
+
+    let mut non_ascii = unix.lines().enumerate().flat_map(|(index, line)| {
+        line.chars()
+            .enumerate()
+            .filter(|(_, c)| !c.is_ascii())
+            .map(move |(column, _)| (index + 1, column + 1))
+    });
+    if let Some((line, column)) = non_ascii.next() {
+        issues.push(Issue::NonAscii {
+            line,
+            column,
+            count: non_ascii.count() + 1,
+        });
+    }
+
+    issues
+}
+
+/// Fixes the [fixable](Issue::is_fixable) issues of `contents`: removes a byte order mark,
+/// converts line endings to `\n` and ends the input with exactly one newline.
+pub fn normalize(contents: &str) -> String {
+    let body = contents.strip_prefix(BOM).unwrap_or(contents);
+    let unix = body.replace("\r\n", "\n");
+    let trimmed = unix.trim_end_matches('\n');
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_clean() {
+        assert_eq!(lint(b"1000\n2000\n\n3000\n"), vec![]);
+        assert_eq!(lint(b""), vec![]);
+    }
+
+    #[test]
+    fn test_lint() {
+        assert_eq!(
+            lint("\u{feff}1000\r\n2000\r\n\r\n3000".as_bytes()),
+            vec![
+                Issue::Bom,
+                Issue::Crlf { lines: 3 },
+                Issue::MissingTrailingNewline
+            ]
+        );
+        assert_eq!(
+            lint(b"$ cd /\n$ ls\ndir\ta\n\t14848514 b.txt\n\n\n"),
+            vec![
+                Issue::ExtraTrailingNewlines { extra: 2 },
+                Issue::Tabs {
+                    first_line: 3,
+                    lines: 2
+                }
+            ]
+        );
+        assert_eq!(
+            lint("move 1 from 2 to 1\nmöve 3 from 1 to 3 – now\n".as_bytes()),
+            vec![Issue::NonAscii {
+                line: 2,
+                column: 2,
+                count: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lint_invalid_utf8() {
+        let issues = lint(b"30373\n2\xffx5512\r\n");
+        assert_eq!(
+            issues,
+            vec![
+                Issue::Crlf { lines: 1 },
+                Issue::NonAscii {
+                    line: 2,
+                    column: 2,
+                    count: 1
+                },
+                Issue::InvalidUtf8 { line: 2, column: 2 }
+            ]
+        );
+        assert!(!issues[2].is_fixable());
+        assert_eq!(
+            issues[2].to_string(),
+            "not valid UTF-8 from line 2, column 2 on"
+        );
+        assert_eq!(
+            lint(b"caf\xc3\xa9 \xc3"),
+            vec![
+                Issue::MissingTrailingNewline,
+                Issue::NonAscii {
+                    line: 1,
+                    column: 4,
+                    count: 2
+                },
+                Issue::InvalidUtf8 { line: 1, column: 6 }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let normalized = normalize("\u{feff}1000\r\n2000\r\n\r\n3000\r\n\r\n");
+        assert_eq!(normalized, "1000\n2000\n\n3000\n");
+        assert_eq!(lint(normalized.as_bytes()), vec![]);
+        assert_eq!(normalize("A Y\nB X"), "A Y\nB X\n");
+        assert_eq!(normalize("a\tb\n"), "a\tb\n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+}
@@ -2,45 +2,240 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::bench;
+use advent_of_code::days;
+use advent_of_code::options::Options;
+use advent_of_code::registry::Registry;
+use advent_of_code::report::Format;
+use advent_of_code::runner::{run_days, solve_day, solve_parts, DayOutcome, DEFAULT_TIMEOUT};
+use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR};
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+/// Registers the solvers of each day below `advent_of_code::days` by year.
+macro_rules! days {
+    ($(($year:literal, $day:literal) => $($module:ident)::+,)*) => {
+        fn registry(year: u16) -> Registry {
+            let mut registry = Registry::new();
+            $(
+                if $year == year {
+                    registry.register(
+                        $day,
+                        days::$($module)::+::part_one,
+                        days::$($module)::+::part_two,
+                    );
+                }
+            )*
+            registry
+        }
+    };
+}
+
+days! {
+    (2022, 1) => day01,
+    (2022, 2) => day02,
+    (2022, 3) => day03,
+    (2022, 4) => day04,
+    (2022, 5) => day05,
+    (2022, 6) => day06,
+    (2022, 7) => day07,
+    (2022, 8) => day08,
+    (2022, 9) => day09,
+    (2022, 10) => day10,
+    (2022, 11) => day11,
+}
+
+struct Args {
+    year: u16,
+    jobs: NonZeroUsize,
+    timeout: Duration,
+    /// Solve only this day with the input from stdin, see [`solve_day`].
+    day: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        jobs: args
+            .opt_value_from_str(["-j", "--jobs"])?
+            .unwrap_or(NonZeroUsize::MIN),
+        timeout: args
+            .opt_value_from_str("--timeout")?
+            .map_or(DEFAULT_TIMEOUT, Duration::from_secs),
+        day: args.opt_value_from_str("--day")?,
+    })
+}
+
+/// Solves a single day in the child process started by [`solve_day`].
+fn solve_child(registry: &Registry, year: u16, day: u8, options: &Options) {
+    let solution = match registry.get(day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!("Day {:02} is not registered.", day);
+            process::exit(1);
+        }
+    };
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("could not read input from stdin: {}", e);
+        process::exit(1);
+    }
+    solve_parts(solution, year, &input, options);
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let options = Options::from_env();
+    if options.input.is_some() {
+        eprintln!("`--input` selects the input of a single day. Use `cargo solve <day> -- --input <path>` or set {} instead.", advent_of_code::INPUT_DIR_VAR);
+        process::exit(1);
+    }
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let registry = registry(args.year);
+    if let Some(day) = args.day {
+        solve_child(&registry, args.year, day, options);
+        return;
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("could not locate the runner executable: {}", e);
+            process::exit(1);
+        }
+    };
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let is_text = options.format == Format::Text;
+    let days: Vec<u8> = (1..=25).collect();
+    let mut total = Duration::ZERO;
+    let mut bench_rows = vec![];
+    let mut checks = vec![];
+    let mut failures = vec![];
+    let timer = Instant::now();
+
+    run_days(
+        &days,
+        args.jobs.get(),
+        |day| solve_day(&registry, &exe, args.year, day, options, args.timeout),
+        |day, outcome| {
+            if is_text {
+                println!("----------");
+                println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+                println!("----------");
+            }
+
+            let (results, failure) = match outcome {
+                DayOutcome::NotSolved => {
+                    if is_text {
+                        println!("Not solved.");
+                    }
+                    return;
                 }
-            );
+                DayOutcome::NoInput(e) => {
+                    if is_text {
+                        println!("Could not read input: {}", e);
+                    } else {
+                        eprintln!("Day {:02}: could not read input: {}", day, e);
+                    }
+                    return;
+                }
+                DayOutcome::Parts(results) => (results, None),
+                DayOutcome::Timeout { parts, after } => (
+                    parts,
+                    Some(("TIMEOUT", format!("stopped after {:.2?}", after))),
+                ),
+                DayOutcome::Panic { parts, message } => (parts, Some(("PANIC", message))),
+            };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+            for result in results {
+                result.print(options.format);
+                if let Some(check) = &result.check {
+                    checks.push(check.label());
+                }
+                total += match result.stats {
+                    Some(stats) => {
+                        bench_rows.push((day, result.part, stats));
+                        stats.median
+                    }
+                    None => result.elapsed,
+                };
             }
-        })
-        .sum();
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+            if let Some((label, reason)) = failure {
+                if is_text {
+                    println!("{}{}{} ({})", ANSI_BOLD, label, ANSI_RESET, reason);
+                } else {
+                    eprintln!("Day {:02}: {} ({})", day, label, reason);
+                }
+                failures.push(format!("{:02} {}", day, label));
+            }
+        },
     );
+
+    let wall_time = timer.elapsed();
+
+    if is_text && !bench_rows.is_empty() {
+        println!("----------");
+        bench::print_table(&bench_rows);
+    }
+
+    if is_text {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        if args.jobs.get() > 1 {
+            println!(
+                "{}Wall time:{} {}{:.2}ms with {} jobs{}",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                wall_time.as_secs_f64() * 1000_f64,
+                args.jobs,
+                ANSI_RESET
+            );
+        }
+    }
+
+    if is_text && !failures.is_empty() {
+        println!("{}Failed:{} {}", ANSI_BOLD, ANSI_RESET, failures.join(", "));
+    }
+
+    if options.check {
+        if is_text {
+            let count = |label| checks.iter().filter(|&&c| c == label).count();
+            println!(
+                "{}Check:{} {} OK, {} WRONG, {} MISSING",
+                ANSI_BOLD,
+                ANSI_RESET,
+                count("OK"),
+                count("WRONG"),
+                count("MISSING")
+            );
+        }
+        // answers are checked by the child processes, so their results are all the runner knows.
+        if checks.contains(&"WRONG") {
+            process::exit(1);
+        }
+    }
+
+    if !failures.is_empty() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,203 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+
+/// Allocations of one part, measured by [`CountingAllocator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// The most memory the part held at once, not counting memory allocated before it started.
+    pub peak_bytes: usize,
+    /// Sum of the sizes of all allocations, a `realloc` counts as an allocation of the new size.
+    pub total_bytes: usize,
+    pub allocations: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "peak: {}, total: {} in {} allocations",
+            Bytes(self.peak_bytes),
+            Bytes(self.total_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 KiB`.
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.2} {}", value, UNITS[unit])
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Counters {
+    current: usize,
+    peak: usize,
+    total: usize,
+    allocations: usize,
+}
+
+thread_local! {
+    // counted per thread, so parts solved in parallel with `--jobs` don't see each other's allocations.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            current: 0,
+            peak: 0,
+            total: 0,
+            allocations: 0,
+        })
+    };
+}
+
+fn count(update: impl FnOnce(&mut Counters)) {
+    // the counters are gone while the thread shuts down, those allocations are not of interest.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut value = counters.get();
+        update(&mut value);
+        value.peak = value.peak.max(value.current);
+        counters.set(value);
+    });
+}
+
+/// Wraps the system allocator and counts the allocations of the current thread.
+/// Installed as the global allocator by the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(|c| {
+                c.current += layout.size();
+                c.total += layout.size();
+                c.allocations += 1;
+            });
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(|c| {
+                c.current += layout.size();
+                c.total += layout.size();
+                c.allocations += 1;
+            });
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        // memory may be freed by another thread than the one that allocated it.
+        count(|c| c.current = c.current.saturating_sub(layout.size()));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(|c| {
+                c.current = c.current.saturating_sub(layout.size()) + new_size;
+                c.total += new_size;
+                c.allocations += 1;
+            });
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Snapshot of the counters of the current thread, taken before a part runs.
+pub struct Tracker {
+    start: Counters,
+}
+
+impl Tracker {
+    pub fn start() -> Tracker {
+        let mut start = Counters::default();
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            // the peak of the part starts at what is allocated now.
+            value.peak = value.current;
+            counters.set(value);
+            start = value;
+        });
+        Tracker { start }
+    }
+
+    /// The allocations since [`Tracker::start`], `None` unless the `alloc-stats` feature is enabled.
+    pub fn stop(self) -> Option<AllocStats> {
+        if !cfg!(feature = "alloc-stats") {
+            return None;
+        }
+        let end = COUNTERS.try_with(Cell::get).ok()?;
+        Some(AllocStats {
+            peak_bytes: end.peak.saturating_sub(self.start.current),
+            total_bytes: end.total - self.start.total,
+            allocations: end.allocations - self.start.allocations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let stats = AllocStats {
+            peak_bytes: 1536,
+            total_bytes: 3 * 1024 * 1024,
+            allocations: 12,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "peak: 1.50 KiB, total: 3.00 MiB in 12 allocations"
+        );
+        assert_eq!(Bytes(512).to_string(), "512 B");
+    }
+
+    #[test]
+    fn test_counting() {
+        let tracker = Tracker::start();
+        unsafe {
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 128);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+        }
+        let end = COUNTERS.with(Cell::get);
+
+        assert_eq!(end.total - tracker.start.total, 192);
+        assert_eq!(end.allocations - tracker.start.allocations, 2);
+        assert!(end.peak - tracker.start.current >= 128);
+        assert_eq!(
+            tracker.stop().is_some(),
+            cfg!(feature = "alloc-stats"),
+            "stats are only reported with the feature enabled"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::Format;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Where a day binary reads its puzzle input from, set with `--input <path>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `--input -`
+    Stdin,
+    Path(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+/// Command line flags shared by the day binaries and the `all` runner.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub format: Format,
+    /// Run every part repeatedly and report timing statistics.
+    pub bench: bool,
+    /// Compare answers with the accepted answers in `src/answers`.
+    pub check: bool,
+    /// Read the input from this source instead of `src/inputs`.
+    pub input: Option<InputSource>,
+}
+
+impl Options {
+    /// Consumes the shared flags from `args`, leaving binary-specific arguments in place.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Options {
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            bench: args.contains("--bench"),
+            check: args.contains("--check"),
+            input: args.opt_value_from_str("--input")?,
+        })
+    }
+
+    /// Returns the options of the current process, parsing them on first use.
+    pub fn from_env() -> &'static Options {
+        static OPTIONS: OnceLock<Options> = OnceLock::new();
+        OPTIONS.get_or_init(|| {
+            let mut args = pico_args::Arguments::from_env();
+            match Options::parse(&mut args) {
+                Ok(options) => options,
+                Err(e) => {
+                    eprintln!("Failed to process arguments: {}", e);
+                    process::exit(1);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn args(values: &[&str]) -> pico_args::Arguments {
+        pico_args::Arguments::from_vec(values.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_parse() {
+        let options = Options::parse(&mut args(&[])).unwrap();
+        assert_eq!(options.format, Format::Text);
+        assert!(!options.bench);
+        assert!(!options.check);
+        assert_eq!(options.input, None);
+
+        let mut rest = args(&["--check", "--format", "json", "7", "--bench"]);
+        let options = Options::parse(&mut rest).unwrap();
+        assert_eq!(options.format, Format::Json);
+        assert!(options.bench);
+        assert!(options.check);
+
+        let options = Options::parse(&mut args(&["--input", "-"])).unwrap();
+        assert_eq!(options.input, Some(InputSource::Stdin));
+
+        let options = Options::parse(&mut args(&["--input", "stress/08.txt"])).unwrap();
+        assert_eq!(
+            options.input,
+            Some(InputSource::Path(PathBuf::from("stress/08.txt")))
+        );
+        assert_eq!(rest.finish(), vec![OsString::from("7")]);
+
+        assert!(Options::parse(&mut args(&["--format", "xml"])).is_err());
+    }
+}
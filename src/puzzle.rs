@@ -0,0 +1,154 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use regex::Regex;
+
+/// Example input and answers found in a puzzle description saved as Markdown.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The day from the `--- Day N: Title ---` heading.
+    pub day: Option<u8>,
+    pub input: Option<String>,
+    pub answers: Answers,
+}
+
+impl Example {
+    /// Extracts the example from a puzzle description in Markdown.
+    ///
+    /// The example input is the first code block of part one. The expected answer of a part
+    /// is the last emphasized code span in its description, e.g. `` `*157*` ``.
+    pub fn parse(markdown: &str) -> Example {
+        let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+            Some(i) => (&markdown[..i], Some(&markdown[i..])),
+            None => (markdown, None),
+        };
+
+        Example {
+            day: parse_day(markdown),
+            input: code_blocks(part_one).into_iter().next(),
+            answers: Answers {
+                part_one: last_emphasized_code(part_one),
+                part_two: part_two.and_then(last_emphasized_code),
+            },
+        }
+    }
+}
+
+fn parse_day(markdown: &str) -> Option<u8> {
+    let start = markdown.find("--- Day ")? + "--- Day ".len();
+    let end = start + markdown[start..].find(':')?;
+    markdown[start..end].trim().parse().ok()
+}
+
+/// Returns the contents of all fenced code blocks without trailing empty lines.
+fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<Vec<&str>> = None;
+    for line in markdown.lines() {
+        if line.trim_end() == "```" {
+            match current.take() {
+                Some(mut lines) => {
+                    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                        lines.pop();
+                    }
+                    blocks.push(lines.join("\n"));
+                }
+                None => current = Some(vec![]),
+            }
+        } else if let Some(lines) = &mut current {
+            lines.push(line);
+        }
+    }
+    blocks
+}
+
+/// Finds the last `` `*text*` `` or `` *`text`* `` span outside of code blocks.
+fn last_emphasized_code(markdown: &str) -> Option<String> {
+    let re = Regex::new(r"`\*([^`*]+)\*`|\*`([^`]+)`\*").unwrap();
+    let mut in_block = false;
+    let mut last = None;
+    for line in markdown.lines() {
+        if line.trim_end() == "```" {
+            in_block = !in_block;
+        } else if !in_block {
+            if let Some(captures) = re.captures_iter(line).last() {
+                let answer = captures.get(1).or_else(|| captures.get(2)).unwrap();
+                last = Some(answer.as_str().to_string());
+            }
+        }
+    }
+    last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"\--- Day 4: Camp Cleanup ---
+----------
+
+For example, consider the following list of section assignment pairs:
+
+```
+2-4,6-8
+2-3,4-5
+
+```
+
+Visually, these pairs of section assignments look like this:
+
+```
+.234.....  2-4
+```
+
+For example, `2-8` fully contains `3-7`. In this example, there are `*2*` such pairs.
+
+*In how many assignment pairs does one range fully contain the other?*
+
+Your puzzle answer was `305`.
+
+\--- Part Two ---
+----------
+
+In this example, the number of overlapping assignment pairs is *`4`*.
+
+*In how many assignment pairs do the ranges overlap?*
+"#;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse(PUZZLE);
+        assert_eq!(example.day, Some(4));
+        assert_eq!(example.input.as_deref(), Some("2-4,6-8\n2-3,4-5"));
+        assert_eq!(example.answers.get(1), Some("2"));
+        assert_eq!(example.answers.get(2), Some("4"));
+    }
+
+    #[test]
+    fn test_parse_part_one_only() {
+        let puzzle = PUZZLE.split("\\--- Part Two ---").next().unwrap();
+        let example = Example::parse(puzzle);
+        assert_eq!(example.answers.get(1), Some("2"));
+        assert_eq!(example.answers.get(2), None);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(Example::parse("no puzzle here"), Example::default());
+    }
+
+    #[test]
+    fn test_last_emphasized_code() {
+        assert_eq!(
+            last_emphasized_code("`*CMZ*` then *`MCD`* and `plain`"),
+            Some("MCD".to_string())
+        );
+        assert_eq!(
+            last_emphasized_code("*`MCD`* then `*CMZ*`"),
+            Some("CMZ".to_string())
+        );
+        assert_eq!(last_emphasized_code("*emphasis* and `code`"), None);
+    }
+}
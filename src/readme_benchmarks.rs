@@ -0,0 +1,153 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::PartResult;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Encloses the benchmark table in the readme, the table is written between two of these.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+/// The median of a benchmarked part, the single run of any other.
+fn part_time(result: &PartResult) -> Duration {
+    match &result.stats {
+        Some(stats) => stats.median,
+        None => result.elapsed,
+    }
+}
+
+/// Renders the results of the `all` runner as a Markdown table with a row for every day up to `last_day`,
+/// or up to the last day with a result if that is later.
+/// Parts that were not solved, e.g. because their day panicked before printing a result,
+/// are listed without a time and left out of the totals.
+pub fn table(results: &[PartResult], last_day: u8) -> String {
+    let last_day = results
+        .iter()
+        .map(|result| result.day)
+        .fold(last_day, u8::max);
+    let mut days: BTreeMap<u8, Vec<&PartResult>> =
+        (1..=last_day).map(|day| (day, vec![])).collect();
+    for result in results {
+        days.entry(result.day).or_default().push(result);
+    }
+
+    let mut table = String::new();
+    table.push_str("| Day | Part 1 | Part 2 | Total |\n");
+    table.push_str("| :---: | ---: | ---: | ---: |\n");
+
+    let mut total = Duration::ZERO;
+    for (day, parts) in &days {
+        let mut day_total = Duration::ZERO;
+        let mut cells = vec![];
+        for part in [1, 2] {
+            let cell = match parts.iter().find(|result| result.part == part) {
+                Some(result) if result.is_solved() => {
+                    day_total += part_time(result);
+                    format!("`{:.2?}`", part_time(result))
+                }
+                _ => "not solved".to_string(),
+            };
+            cells.push(cell);
+        }
+        total += day_total;
+        writeln!(
+            table,
+            "| {:02} | {} | {} | `{:.2?}` |",
+            day, cells[0], cells[1], day_total
+        )
+        .unwrap();
+    }
+
+    writeln!(table, "| **Total** | | | **`{:.2?}`** |", total).unwrap();
+    table
+}
+
+/// Replaces the contents between the two markers of `readme` with `table`.
+/// Returns `None` if the readme does not contain both markers.
+pub fn update(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(MARKER)? + MARKER.len();
+    let end = start + readme[start..].find(MARKER)?;
+    Some(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn result(day: u8, part: u8, answer: Option<&str>, micros: u64) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer: answer.map(str::to_string),
+            error: None,
+            elapsed: Duration::from_micros(micros),
+            stats: None,
+            check: None,
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let mut benchmarked = result(2, 1, Some("15"), 900);
+        benchmarked.stats = Stats::from_timings(&[Duration::from_micros(10)]);
+        let results = vec![
+            result(1, 1, Some("24000"), 20),
+            result(1, 2, Some("45000"), 30),
+            benchmarked,
+            result(2, 2, None, 5),
+        ];
+
+        assert_eq!(
+            table(&results, 2),
+            "| Day | Part 1 | Part 2 | Total |\n\
+             | :---: | ---: | ---: | ---: |\n\
+             | 01 | `20.00µs` | `30.00µs` | `50.00µs` |\n\
+             | 02 | `10.00µs` | not solved | `10.00µs` |\n\
+             | **Total** | | | **`60.00µs`** |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_gaps() {
+        let results = vec![
+            result(1, 1, Some("24000"), 20),
+            result(1, 2, Some("45000"), 30),
+            result(3, 2, Some("70"), 10),
+        ];
+
+        assert_eq!(
+            table(&results, 4),
+            "| Day | Part 1 | Part 2 | Total |\n\
+             | :---: | ---: | ---: | ---: |\n\
+             | 01 | `20.00µs` | `30.00µs` | `50.00µs` |\n\
+             | 02 | not solved | not solved | `0.00ns` |\n\
+             | 03 | not solved | `10.00µs` | `10.00µs` |\n\
+             | 04 | not solved | not solved | `0.00ns` |\n\
+             | **Total** | | | **`60.00µs`** |\n"
+        );
+        assert_eq!(table(&results, 0), table(&results, 3));
+    }
+
+    #[test]
+    fn test_update() {
+        let readme = format!("# AoC\n\n{}\n{}\n\n## Usage\n", MARKER, MARKER);
+        let table = table(&[result(1, 1, Some("24000"), 20)], 1);
+
+        let updated = update(&readme, &table).unwrap();
+        assert!(updated.starts_with(&format!("# AoC\n\n{}\n\n| Day |", MARKER)));
+        assert!(updated.ends_with(&format!("|\n\n{}\n\n## Usage\n", MARKER)));
+        assert_eq!(update(&updated, &table).unwrap(), updated);
+
+        assert_eq!(update("# AoC\n", &table), None);
+        assert_eq!(update(&format!("# AoC\n{}\n", MARKER), &table), None);
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::memory::{AllocStats, Tracker};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// The outcome of calling a solver once: its answer (if solved) and how long it took.
+pub struct Run {
+    pub answer: Option<String>,
+    /// The error returned by a solver that returns a `Result`.
+    pub error: Option<String>,
+    pub elapsed: Duration,
+    /// What the solver allocated, present when built with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+/// Types a solver may return: `Option<T>`, where `None` means "not solved", or `Result<T, E>`,
+/// where the error (e.g. a [`crate::error::ParseError`]) is reported instead of an answer.
+/// A solver that parses its input but is not solved yet returns `Result<Option<T>, E>`.
+pub trait Answer {
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<A: Answer, E: Display> Answer for Result<A, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map_err(|e| e.to_string())?.into_answer()
+    }
+}
+
+// `Result<T, E>` can't take any `T: Display` next to `Option<T>`, so the answers themselves are listed here.
+macro_rules! impl_answer {
+    ($($t:ty)*) => {$(
+        impl Answer for $t {
+            fn into_answer(self) -> Result<Option<String>, String> {
+                Ok(Some(self.to_string()))
+            }
+        }
+    )*};
+}
+
+impl_answer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize char String &str);
+
+/// A solver with its answer type erased, so solutions returning different types can share a registry.
+pub type Solver = Box<dyn Fn(&str) -> Run + Send + Sync>;
+
+/// Calls `func` with `input` and times it. The answer is converted to a string after the timer stops.
+pub fn run<A: Answer>(func: impl Fn(&str) -> A, input: &str) -> Run {
+    let tracker = Tracker::start();
+    let timer = Instant::now();
+    let result = func(input);
+    let elapsed = timer.elapsed();
+    let alloc = tracker.stop();
+    match result.into_answer() {
+        Ok(answer) => Run {
+            answer,
+            error: None,
+            elapsed,
+            alloc,
+        },
+        Err(error) => Run {
+            answer: None,
+            error: Some(error),
+            elapsed,
+            alloc,
+        },
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    /// Returns the solvers as `(part, solver)` pairs.
+    pub fn parts(&self) -> [(u8, &Solver); 2] {
+        [(1, &self.part_one), (2, &self.part_two)]
+    }
+}
+
+/// Maps days to their solvers so all solutions can be run in-process.
+#[derive(Default)]
+pub struct Registry {
+    days: Vec<Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the `part_one` and `part_two` functions of `day`, replacing any previous registration.
+    pub fn register<A: Answer, B: Answer>(
+        &mut self,
+        day: u8,
+        part_one: impl Fn(&str) -> A + Send + Sync + 'static,
+        part_two: impl Fn(&str) -> B + Send + Sync + 'static,
+    ) {
+        self.days.retain(|d| d.day != day);
+        self.days.push(Day {
+            day,
+            part_one: Box::new(move |input| run(&part_one, input)),
+            part_two: Box::new(move |input| run(&part_two, input)),
+        });
+        self.days.sort_by_key(|d| d.day);
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// Iterates registered days in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Day> {
+        self.days.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    fn unsolved(_input: &str) -> Option<u32> {
+        None
+    }
+
+    #[test]
+    fn test_register_and_run() {
+        let mut registry = Registry::new();
+        registry.register(2, unsolved, answer);
+        registry.register(1, answer, |input: &str| Some(input.to_uppercase()));
+
+        let days: Vec<u8> = registry.iter().map(|d| d.day).collect();
+        assert_eq!(days, vec![1, 2]);
+
+        let day = registry.get(1).unwrap();
+        assert_eq!((day.part_one)("abc").answer, Some("3".to_string()));
+        assert_eq!((day.part_two)("abc").answer, Some("ABC".to_string()));
+
+        let day = registry.get(2).unwrap();
+        assert_eq!((day.part_one)("abc").answer, None);
+        assert!(registry.get(3).is_none());
+    }
+
+    #[test]
+    fn test_run_result() {
+        let parse = |input: &str| input.parse::<u32>().map_err(|_| "not a number");
+
+        let run = super::run(parse, "42");
+        assert_eq!(run.answer, Some("42".to_string()));
+        assert_eq!(run.error, None);
+
+        let run = super::run(parse, "x");
+        assert_eq!(run.answer, None);
+        assert_eq!(run.error, Some("not a number".to_string()));
+
+        let run = super::run(|input: &str| parse(input).map(|_| None::<u32>), "42");
+        assert_eq!((run.answer, run.error), (None, None));
+    }
+
+    #[test]
+    fn test_register_replaces_day() {
+        let mut registry = Registry::new();
+        registry.register(1, unsolved, unsolved);
+        registry.register(1, answer, answer);
+
+        assert_eq!(registry.iter().count(), 1);
+        assert_eq!(
+            (registry.get(1).unwrap().part_one)("ab").answer,
+            Some("2".to_string())
+        );
+    }
+}
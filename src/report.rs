@@ -0,0 +1,405 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::CheckStatus;
+use crate::bench::Stats;
+use crate::memory::AllocStats;
+use crate::registry::Run;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line, see [`PartResult::to_json`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format \"{}\", expected \"text\" or \"json\"",
+                s
+            )),
+        }
+    }
+}
+
+/// Machine-readable record of solving one part of a day, serialized as described in [`PartResult::to_json`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "Record", try_from = "Record")]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The error a `Result`-returning solver failed with.
+    pub error: Option<String>,
+    pub elapsed: Duration,
+    /// Timing statistics, present when the part was benchmarked with `--bench`.
+    pub stats: Option<Stats>,
+    /// Comparison with the accepted answer, present when run with `--check`.
+    pub check: Option<CheckStatus>,
+    /// Allocations of the part, present when built with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl PartResult {
+    pub fn new(day: u8, part: u8, run: Run) -> Self {
+        PartResult {
+            day,
+            part,
+            answer: run.answer,
+            error: run.error,
+            elapsed: run.elapsed,
+            stats: None,
+            check: None,
+            alloc: run.alloc,
+        }
+    }
+
+    pub fn with_stats(self, stats: Stats) -> Self {
+        PartResult {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Serializes the record as a single-line JSON object, e.g.
+    /// `{"day":1,"part":2,"answer":"45000","elapsed_ns":5210,"solved":true}`.
+    /// Benchmarked parts carry an additional `bench` object with their statistics,
+    /// checked parts a `check` status and the `expected` answer if it was wrong.
+    /// Failed parts carry an `error` message, parts measured with the `alloc-stats` feature an `alloc` object.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a part result is always valid JSON")
+    }
+
+    /// Parses a record written by [`PartResult::to_json`].
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line).ok()
+    }
+
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Text => {
+                println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET);
+                match &self.answer {
+                    Some(answer) => match &self.alloc {
+                        Some(alloc) => println!(
+                            "{} {}(elapsed: {:.2?}) ({}){}",
+                            answer, ANSI_ITALIC, self.elapsed, alloc, ANSI_RESET
+                        ),
+                        None => println!(
+                            "{} {}(elapsed: {:.2?}){}",
+                            answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+                        ),
+                    },
+                    None => match &self.error {
+                        Some(error) => {
+                            println!("{}{}{}", ANSI_BOLD, error, ANSI_RESET)
+                        }
+                        None => println!("not solved."),
+                    },
+                }
+                match &self.check {
+                    Some(CheckStatus::Wrong { expected }) => {
+                        println!("{}WRONG{} (expected: {})", ANSI_BOLD, ANSI_RESET, expected)
+                    }
+                    Some(status) => println!("{}{}{}", ANSI_BOLD, status.label(), ANSI_RESET),
+                    None => {}
+                }
+                if let Some(stats) = &self.stats {
+                    println!(
+                        "{}min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?} ({} samples){}",
+                        ANSI_ITALIC,
+                        stats.min,
+                        stats.median,
+                        stats.mean,
+                        stats.stddev,
+                        stats.samples,
+                        ANSI_RESET
+                    );
+                }
+            }
+            Format::Json => {
+                let line = self.to_json() + "\n";
+                // the whole line in one write, so records of parallel parts don't interleave.
+                let written = match records() {
+                    Some(mut records) => records.write_all(line.as_bytes()),
+                    None => io::stdout().write_all(line.as_bytes()),
+                };
+                if let Err(e) = written {
+                    eprintln!("could not write result: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Keeps stdout for the records of `--format json`: everything else printed to stdout from now on,
+/// e.g. debug output of a solution, goes to stderr instead. Call it before the first part runs.
+/// Only supported on Unix, elsewhere records and other output share stdout.
+pub fn reserve_stdout_for_records() {
+    records();
+}
+
+/// The original stdout after [`reserve_stdout_for_records`], or `None` if it could not be reserved.
+fn records() -> Option<&'static File> {
+    static RECORDS: OnceLock<Option<File>> = OnceLock::new();
+    RECORDS.get_or_init(redirect_stdout).as_ref()
+}
+
+#[cfg(unix)]
+fn redirect_stdout() -> Option<File> {
+    use std::os::fd::FromRawFd;
+
+    // hold the lock so nothing is written to stdout while it is replaced.
+    let mut stdout = io::stdout().lock();
+    stdout.flush().ok()?;
+    // SAFETY: `dup` returns a new descriptor that is owned by the returned `File` alone,
+    // `dup2` only replaces descriptor 1, which `stdout` keeps writing to.
+    unsafe {
+        let records = libc::dup(libc::STDOUT_FILENO);
+        if records < 0 {
+            return None;
+        }
+        if libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            libc::close(records);
+            return None;
+        }
+        Some(File::from_raw_fd(records))
+    }
+}
+
+#[cfg(not(unix))]
+fn redirect_stdout() -> Option<File> {
+    None
+}
+
+/// Serializes a `Duration` as whole nanoseconds.
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// The JSON form of a [`PartResult`], which adds `solved` and spreads the check over `check` and `expected`.
+#[derive(Serialize, Deserialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    #[serde(with = "nanos")]
+    elapsed_ns: Duration,
+    #[serde(default)]
+    solved: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bench: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    alloc: Option<AllocStats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    check: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+}
+
+impl From<PartResult> for Record {
+    fn from(result: PartResult) -> Self {
+        let expected = match &result.check {
+            Some(CheckStatus::Wrong { expected }) => Some(expected.clone()),
+            _ => None,
+        };
+        Record {
+            solved: result.is_solved(),
+            day: result.day,
+            part: result.part,
+            answer: result.answer,
+            elapsed_ns: result.elapsed,
+            error: result.error,
+            bench: result.stats,
+            alloc: result.alloc,
+            check: result.check.map(|check| check.label().to_string()),
+            expected,
+        }
+    }
+}
+
+impl TryFrom<Record> for PartResult {
+    type Error = String;
+
+    fn try_from(record: Record) -> Result<Self, Self::Error> {
+        let check = match (record.check.as_deref(), record.expected) {
+            (None, _) => None,
+            (Some("OK"), _) => Some(CheckStatus::Ok),
+            (Some("MISSING"), _) => Some(CheckStatus::Missing),
+            (Some("WRONG"), Some(expected)) => Some(CheckStatus::Wrong { expected }),
+            (Some(label), _) => return Err(format!("invalid check \"{}\"", label)),
+        };
+        Ok(PartResult {
+            day: record.day,
+            part: record.part,
+            answer: record.answer,
+            error: record.error,
+            elapsed: record.elapsed_ns,
+            stats: record.bench,
+            check,
+            alloc: record.alloc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let result = PartResult {
+            day: 5,
+            part: 1,
+            answer: Some("CMZ".to_string()),
+            error: None,
+            elapsed: Duration::from_nanos(1_234),
+            stats: None,
+            check: None,
+            alloc: None,
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":"CMZ","elapsed_ns":1234,"solved":true}"#
+        );
+
+        let result = PartResult {
+            answer: None,
+            ..result
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":null,"elapsed_ns":1234,"solved":false}"#
+        );
+
+        let stats = Stats::from_timings(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let result = result.with_stats(stats.unwrap());
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":null,"elapsed_ns":1234,"solved":false,"bench":{"samples":2,"min_ns":10,"median_ns":20,"mean_ns":20,"stddev_ns":10}}"#
+        );
+
+        let result = PartResult {
+            answer: Some("MCD".to_string()),
+            stats: None,
+            check: Some(CheckStatus::Wrong {
+                expected: "CMZ".to_string(),
+            }),
+            ..result
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":"MCD","elapsed_ns":1234,"solved":true,"check":"WRONG","expected":"CMZ"}"#
+        );
+
+        let result = PartResult {
+            answer: None,
+            error: Some("parse error on line 2: `move 1`".to_string()),
+            check: None,
+            ..result
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":null,"elapsed_ns":1234,"solved":false,"error":"parse error on line 2: `move 1`"}"#
+        );
+
+        let result = PartResult {
+            answer: Some("CMZ".to_string()),
+            error: None,
+            alloc: Some(AllocStats {
+                peak_bytes: 2048,
+                total_bytes: 4096,
+                allocations: 3,
+            }),
+            ..result
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":5,"part":1,"answer":"CMZ","elapsed_ns":1234,"solved":true,"alloc":{"peak_bytes":2048,"total_bytes":4096,"allocations":3}}"#
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let stats = Stats::from_timings(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let result = PartResult {
+            day: 5,
+            part: 2,
+            answer: Some("M\"CD".to_string()),
+            error: None,
+            elapsed: Duration::from_nanos(1_234),
+            stats,
+            check: Some(CheckStatus::Wrong {
+                expected: "CMZ".to_string(),
+            }),
+            alloc: Some(AllocStats {
+                peak_bytes: 2048,
+                total_bytes: 4096,
+                allocations: 3,
+            }),
+        };
+        assert_eq!(PartResult::from_json(&result.to_json()), Some(result));
+
+        let result = PartResult {
+            day: 11,
+            part: 1,
+            answer: None,
+            error: Some("parse error on line 3: `x`".to_string()),
+            elapsed: Duration::ZERO,
+            stats: None,
+            check: None,
+            alloc: None,
+        };
+        assert_eq!(PartResult::from_json(&result.to_json()), Some(result));
+
+        assert_eq!(PartResult::from_json("🎄 Part 1 🎄"), None);
+        assert_eq!(PartResult::from_json(r#"{"day":1}"#), None);
+        assert_eq!(
+            PartResult::from_json(
+                r#"{"day":1,"part":1,"answer":"1","elapsed_ns":5,"check":"WRONG"}"#
+            ),
+            None
+        );
+
+        let escaped = r#"{"day":1,"part":1,"answer":"tab\t\u00e9\ud83c\udf84","elapsed_ns":5}"#;
+        let result = PartResult::from_json(escaped).unwrap();
+        assert_eq!(result.answer.as_deref(), Some("tab\té🎄"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
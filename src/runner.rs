@@ -0,0 +1,300 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::options::Options;
+use crate::registry::{Day, Registry};
+use crate::report::{Format, PartResult};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a day may run in the `all` runner before it is stopped, change it with `--timeout <seconds>`.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// What solving a single day in the `all` runner produced.
+pub enum DayOutcome {
+    /// The day is not registered.
+    NotSolved,
+    /// The day's input could not be read.
+    NoInput(io::Error),
+    Parts(Vec<PartResult>),
+    /// The day was stopped after running for `after`. `parts` holds the parts solved before.
+    Timeout {
+        parts: Vec<PartResult>,
+        after: Duration,
+    },
+    /// The day panicked or exited with a non-zero status. `parts` holds the parts solved before.
+    Panic {
+        parts: Vec<PartResult>,
+        message: String,
+    },
+}
+
+/// Solves both parts of a day and prints each result as a JSON line as soon as it is known.
+/// This is what the child process started by [`solve_day`] runs.
+pub fn solve_parts(solution: &Day, year: u16, input: &str, options: &Options) {
+    for (part, solver) in solution.parts() {
+        crate::solve_part(year, solution.day, part, solver, input, options).print(Format::Json);
+    }
+}
+
+/// Reads the input of `day` of `year` and solves both of its parts in a child process, `exe --day <day>`,
+/// so a day that panics, exits or runs longer than `timeout` does not take the runner down with it.
+/// Anything else the day prints is discarded.
+pub fn solve_day(
+    registry: &Registry,
+    exe: &Path,
+    year: u16,
+    day: u8,
+    options: &Options,
+    timeout: Duration,
+) -> DayOutcome {
+    if registry.get(day).is_none() {
+        return DayOutcome::NotSolved;
+    }
+
+    let input = match crate::try_read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => return DayOutcome::NoInput(e),
+    };
+
+    let mut command = Command::new(exe);
+    command
+        .args(["--year", &year.to_string(), "--day", &day.to_string()])
+        .args(["--format", "json"]);
+    if options.bench {
+        command.arg("--bench");
+    }
+    if options.check {
+        command.arg("--check");
+    }
+    let mut child = match command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            return DayOutcome::Panic {
+                parts: vec![],
+                message: format!("could not start {}: {}", exe.display(), e),
+            }
+        }
+    };
+
+    // pipes are written and read on their own threads, so a day that prints a lot can't block on a full pipe.
+    let stdin = child.stdin.take().map(|mut stdin| {
+        thread::spawn(move || {
+            // fails if the day exits before reading its input, its exit status tells why.
+            let _ = stdin.write_all(input.as_bytes());
+        })
+    });
+    let stdout = child.stdout.take().map(|stdout| {
+        thread::spawn(move || {
+            lines(stdout)
+                .filter_map(|line| PartResult::from_json(&line))
+                .collect::<Vec<_>>()
+        })
+    });
+    let stderr = child
+        .stderr
+        .take()
+        .map(|stderr| thread::spawn(move || scan_stderr(lines(stderr))));
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Ok(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Err(None);
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break Err(Some(e));
+            }
+        }
+    };
+
+    if let Some(stdin) = stdin {
+        let _ = stdin.join();
+    }
+    let parts = stdout
+        .and_then(|stdout| stdout.join().ok())
+        .unwrap_or_default();
+    let (panic, last_line) = stderr
+        .and_then(|stderr| stderr.join().ok())
+        .unwrap_or_default();
+
+    match status {
+        Ok(status) if status.success() => DayOutcome::Parts(parts),
+        Ok(status) => {
+            let message = match (panic, last_line) {
+                (Some(panic), _) => panic,
+                (None, Some(line)) => format!("{}: {}", status, line),
+                (None, None) => status.to_string(),
+            };
+            DayOutcome::Panic { parts, message }
+        }
+        Err(None) => DayOutcome::Timeout {
+            parts,
+            after: timeout,
+        },
+        Err(Some(e)) => DayOutcome::Panic {
+            parts,
+            message: format!("could not wait for the day to finish: {}", e),
+        },
+    }
+}
+
+/// Lines of `pipe`. Invalid UTF-8 is replaced instead of ending the stream, so the pipe is always drained.
+fn lines(pipe: impl Read) -> impl Iterator<Item = String> {
+    BufReader::new(pipe)
+        .split(b'\n')
+        .map_while(Result::ok)
+        .map(|line| String::from_utf8_lossy(&line).trim_end().to_string())
+}
+
+/// Returns the message of the first panic in the `lines` of stderr, e.g. `attempt to add with overflow at src/bin/06.rs:7:9`,
+/// and the last non-empty line.
+fn scan_stderr(mut lines: impl Iterator<Item = String>) -> (Option<String>, Option<String>) {
+    let mut panic = None;
+    let mut last_line = None;
+    while let Some(line) = lines.next() {
+        if panic.is_none() {
+            if let Some((_, location)) = line.split_once("panicked at ") {
+                // the message follows on the next line since Rust 1.73, before that it was part of this one.
+                panic = Some(match location.strip_suffix(':') {
+                    Some(location) => match lines.next() {
+                        Some(message) => format!("{} at {}", message, location),
+                        None => location.to_string(),
+                    },
+                    None => location.to_string(),
+                });
+                continue;
+            }
+        }
+        if !line.trim().is_empty() {
+            last_line = Some(line);
+        }
+    }
+    (panic, last_line)
+}
+
+/// Calls `solve` for every day in `days` on `jobs` threads and passes the results to `report` in day order.
+/// `report` is called as soon as all earlier days are reported, so output streams even when `jobs` is 1.
+pub fn run_days<T: Send>(
+    days: &[u8],
+    jobs: usize,
+    solve: impl Fn(u8) -> T + Sync,
+    mut report: impl FnMut(u8, T),
+) {
+    let next_index = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let tx = tx.clone();
+            let (next_index, solve) = (&next_index, &solve);
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let day = match days.get(index) {
+                    Some(&day) => day,
+                    None => break,
+                };
+                if tx.send((index, solve(day))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_report = 0;
+        for (index, result) in rx {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_report) {
+                report(days[next_report], result);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_days_reports_in_order() {
+        let days: Vec<u8> = (1..=25).collect();
+        let mut reported = vec![];
+
+        run_days(
+            &days,
+            4,
+            |day| {
+                // make early days finish last.
+                thread::sleep(Duration::from_millis(26 - day as u64));
+                day as u32 * 10
+            },
+            |day, result| reported.push((day, result)),
+        );
+
+        let expected: Vec<(u8, u32)> = days.iter().map(|&d| (d, d as u32 * 10)).collect();
+        assert_eq!(reported, expected);
+    }
+
+    #[test]
+    fn test_scan_stderr() {
+        let stderr = [
+            "warning: input was edited",
+            "",
+            "thread 'main' panicked at src/bin/06.rs:7:9:",
+            "attempt to add with overflow",
+            "note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace",
+        ];
+        assert_eq!(
+            scan_stderr(stderr.iter().map(|line| line.to_string())),
+            (
+                Some("attempt to add with overflow at src/bin/06.rs:7:9".to_string()),
+                Some(stderr[4].to_string())
+            )
+        );
+
+        let stderr = ["thread 'main' panicked at 'no marker', src/bin/06.rs:7:9"];
+        assert_eq!(
+            scan_stderr(stderr.iter().map(|line| line.to_string())).0,
+            Some("'no marker', src/bin/06.rs:7:9".to_string())
+        );
+
+        let stderr = ["error: not a number", ""];
+        assert_eq!(
+            scan_stderr(stderr.iter().map(|line| line.to_string())),
+            (None, Some("error: not a number".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_run_days_single_job() {
+        let mut reported = vec![];
+        run_days(
+            &[3, 1, 2],
+            1,
+            |day| day,
+            |day, result| reported.push((day, result)),
+        );
+        assert_eq!(reported, vec![(3, 3), (1, 1), (2, 2)]);
+    }
+}
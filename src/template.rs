@@ -0,0 +1,232 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use regex::Regex;
+
+/// Values substituted into a scaffold template, see `templates/default.rs`.
+pub struct Placeholders<'a> {
+    pub year: u16,
+    pub day: u8,
+    /// The `T` of the generated `Option<T>` solutions.
+    pub return_type: &'a str,
+    pub expected: &'a Answers,
+}
+
+/// Replaces the `{{...}}` placeholders of `template`.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let expected = |part| {
+        expected_literal(
+            placeholders.expected.get(part),
+            "Some",
+            placeholders.return_type,
+        )
+    };
+    template
+        .replace("{{YEAR}}", &placeholders.year.to_string())
+        .replace("{{DAY}}", &placeholders.day.to_string())
+        .replace("{{TYPE}}", placeholders.return_type)
+        .replace("{{EXPECTED_ONE}}", &expected(1))
+        .replace("{{EXPECTED_TWO}}", &expected(2))
+}
+
+/// Renders the expected value of a test assertion, e.g. `Some(157)`, `Ok("CMZ".to_string())` or `None`.
+/// `wrapper` is the variant that holds a solved answer, `value_type` the type of the answer.
+pub fn expected_literal(answer: Option<&str>, wrapper: &str, value_type: &str) -> String {
+    match answer {
+        Some(answer) if value_type != "String" && answer.parse::<i128>().is_ok() => {
+            format!("{}({})", wrapper, answer)
+        }
+        Some(answer) => format!("{}({:?}.to_string())", wrapper, answer),
+        None => "None".to_string(),
+    }
+}
+
+/// Splits a solution's return type like `Option<u32>` or `Result<String, ParseError>`
+/// into the variant holding the answer and the answer's type.
+fn answer_type(return_type: &str) -> Option<(&'static str, String)> {
+    let (outer, rest) = return_type.trim().split_once('<')?;
+    let inner = rest.trim_end().strip_suffix('>')?;
+
+    let mut depth = 0;
+    let end = inner
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '<' | '(' => depth += 1,
+                '>' | ')' => depth -= 1,
+                _ => {}
+            }
+            c == ',' && depth == 0
+        })
+        .map_or(inner.len(), |(i, _)| i);
+
+    let wrapper = match outer.trim() {
+        "Option" => "Some",
+        "Result" => "Ok",
+        _ => return None,
+    };
+    Some((wrapper, inner[..end].trim().to_string()))
+}
+
+/// Rewrites the assertions of `test_part_one` and `test_part_two` in `module` to expect `answers`.
+/// Returns the updated module and the new expected value of every part that was rewritten.
+pub fn update_tests(module: &str, answers: &Answers) -> (String, Vec<(u8, String)>) {
+    let mut module = module.to_string();
+    let mut updated = vec![];
+
+    for (part, name) in [(1, "part_one"), (2, "part_two")] {
+        let answer = match answers.get(part) {
+            Some(answer) => answer,
+            None => continue,
+        };
+
+        let signature =
+            Regex::new(&format!(r"pub fn {}\s*\([^)]*\)\s*->\s*([^{{]+)\{{", name)).unwrap();
+        let (wrapper, value_type) = match signature
+            .captures(&module)
+            .and_then(|captures| answer_type(&captures[1]))
+        {
+            Some(answer_type) => answer_type,
+            None => continue,
+        };
+
+        // only look at the first assertion of the test, other tests may call the part as well.
+        let test_start = match module.find(&format!("fn test_{}()", name)) {
+            Some(start) => start,
+            None => continue,
+        };
+        let assertion = Regex::new(&format!(
+            r"(?s)(assert_eq!\(\s*{}\(&input\),\s*)(.*?)(,?\s*\);)",
+            name
+        ))
+        .unwrap();
+        let captures = match assertion.captures(&module[test_start..]) {
+            Some(captures) => captures,
+            None => continue,
+        };
+
+        let expected = expected_literal(Some(answer), wrapper, &value_type);
+        let value = captures.get(2).unwrap();
+        let range = test_start + value.start()..test_start + value.end();
+        module.replace_range(range, &expected);
+        updated.push((part, expected));
+    }
+
+    (module, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part_one: Option<&str>, part_two: Option<&str>) -> Answers {
+        Answers {
+            part_one: part_one.map(str::to_string),
+            part_two: part_two.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let expected = answers(Some("157"), None);
+        let placeholders = Placeholders {
+            year: 2022,
+            day: 3,
+            return_type: "u64",
+            expected: &expected,
+        };
+        assert_eq!(
+            render(
+                "fn part_one() -> Option<{{TYPE}}> // {{YEAR}}/{{DAY}}: {{EXPECTED_ONE}}, {{EXPECTED_TWO}}",
+                &placeholders
+            ),
+            "fn part_one() -> Option<u64> // 2022/3: Some(157), None"
+        );
+    }
+
+    #[test]
+    fn test_expected_literal() {
+        assert_eq!(expected_literal(Some("157"), "Some", "u32"), "Some(157)");
+        assert_eq!(expected_literal(Some("-3"), "Ok", "i64"), "Ok(-3)");
+        assert_eq!(
+            expected_literal(Some("157"), "Some", "String"),
+            r#"Some("157".to_string())"#
+        );
+        assert_eq!(
+            expected_literal(Some("CMZ"), "Ok", "u32"),
+            r#"Ok("CMZ".to_string())"#
+        );
+        assert_eq!(expected_literal(None, "Some", "u32"), "None");
+    }
+
+    #[test]
+    fn test_answer_type() {
+        assert_eq!(
+            answer_type("Option<u32> "),
+            Some(("Some", "u32".to_string()))
+        );
+        assert_eq!(
+            answer_type("Result<String, ParseError>"),
+            Some(("Ok", "String".to_string()))
+        );
+        assert_eq!(
+            answer_type("Result<Vec<(u8, u8)>, String>"),
+            Some(("Ok", "Vec<(u8, u8)>".to_string()))
+        );
+        assert_eq!(answer_type("u32"), None);
+    }
+
+    #[test]
+    fn test_update_tests() {
+        let module = r#"pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+pub fn part_two(input: &str) -> Result<String, ParseError> {
+    Ok(String::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 5);
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 5);
+        assert_eq!(
+            part_two(&input),
+            Ok("old".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(part_two(&input), Err(ParseError::new(7, "move x")));
+    }
+}
+"#;
+        let (updated, parts) = update_tests(module, &answers(Some("24000"), Some("MCD")));
+        assert_eq!(
+            parts,
+            vec![
+                (1, "Some(24000)".to_string()),
+                (2, r#"Ok("MCD".to_string())"#.to_string())
+            ]
+        );
+        assert!(updated.contains("assert_eq!(part_one(&input), Some(24000));"));
+        assert!(updated.contains("            Ok(\"MCD\".to_string())\n        );"));
+        assert!(updated.contains("Err(ParseError::new(7, \"move x\"))"));
+
+        let (unchanged, parts) = update_tests(module, &answers(None, None));
+        assert_eq!(unchanged, module);
+        assert!(parts.is_empty());
+    }
+}
@@ -0,0 +1,24 @@
+pub fn part_one(input: &str) -> Option<{{TYPE}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_one(&input), {{EXPECTED_ONE}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_two(&input), {{EXPECTED_TWO}});
+    }
+}
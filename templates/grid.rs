@@ -0,0 +1,32 @@
+use crate::helpers::grid::Grid;
+
+fn parse_grid(input: &str) -> Grid {
+    Grid::parse_chars(input).expect("the input should be a rectangular grid")
+}
+
+pub fn part_one(input: &str) -> Option<{{TYPE}}> {
+    let grid = parse_grid(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
+    let grid = parse_grid(input);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_one(&input), {{EXPECTED_ONE}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_two(&input), {{EXPECTED_TWO}});
+    }
+}
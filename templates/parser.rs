@@ -0,0 +1,40 @@
+use nom::{
+    character::complete::{line_ending, not_line_ending},
+    multi::separated_list1,
+    IResult,
+};
+
+fn parse_line(input: &str) -> IResult<&str, &str> {
+    not_line_ending(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+pub fn part_one(input: &str) -> Option<{{TYPE}}> {
+    let (_, lines) = parse(input).expect("could not parse input");
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
+    let (_, lines) = parse(input).expect("could not parse input");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_one(&input), {{EXPECTED_ONE}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_year_file("examples", {{YEAR}}, {{DAY}});
+        assert_eq!(part_two(&input), {{EXPECTED_TWO}});
+    }
+}